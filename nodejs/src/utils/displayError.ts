@@ -1,3 +1,4 @@
+import { inspect } from 'util'
 import { isWasmPanic } from './isWasmPanic'
 
 export function displayError(e: unknown) {
@@ -7,6 +8,6 @@ export function displayError(e: unknown) {
     console.log('[node:panic]', error)
   } else {
     const errorMessageAsJSON = JSON.parse(error.message)
-    console.log('[node:error]', errorMessageAsJSON)
+    console.log('[node:error]', inspect(errorMessageAsJSON, { depth: null }))
   }
 }
@@ -11,29 +11,171 @@ export function exampleSchema(): SchemaAST;
 export function parseSchema(input: string): SchemaAST;
 /**
 * @param {SchemaAST} ast
+* @param {Record<string, string | undefined> | ((name: string) => string | undefined) | undefined} env
+* @returns {Diagnostics}
 */
-export function validateAST(ast: SchemaAST): void;
-export type SchemaASTBlock = { datasource: Datasource };
+export function validateAST(ast: SchemaAST, env?: Record<string, string | undefined> | ((name: string) => string | undefined)): Diagnostics;
+export type SchemaASTBlock = { datasource: Datasource } | { model: Model } | { enum: Enum } | { compositeType: CompositeType } | { generator: Generator };
 
 export interface SchemaAST {
     datasources: Datasource[];
+    models: Model[];
+    enums: Enum[];
+    compositeTypes: CompositeType[];
+    generators: Generator[];
 }
 
+export type Datasource = { _tag: "db"; value: DatasourceDb };
+
 export type Provider = "postgres" | "cockroachdb" | "mysql" | "mariadb" | "sqlserver" | "sqlite" | "mongodb";
 
-export type Url = { _tag: "static"; value: string } | { _tag: "env"; value: string };
+export type Url = { _tag: "static"; value: string; span: Span } | { _tag: "env"; value: string; span: Span };
+
+export type RelationMode = "foreignKeys" | "prisma";
+
+export interface Extension {
+    name: string;
+    version: string | null;
+    schema: string | null;
+    map: string | null;
+    span: Span;
+}
+
+export interface DuplicateProperty {
+    name: string;
+    span: Span;
+    firstSpan: Span;
+}
+
+export interface UnknownProperty {
+    name: string;
+    span: Span;
+}
 
 export interface DatasourceDb {
-    provider: Provider;
-    url: Url;
+    name: string;
+    documentation: string | null;
+    provider: Spanned<Provider> | null;
+    url: Url | null;
     shadowDatabaseUrl: Url | null;
+    directUrl: Url | null;
+    relationMode: Spanned<RelationMode> | null;
+    extensions: Extension[];
+    schemas: Spanned<string>[];
+    unknownProperties: UnknownProperty[];
+    duplicateProperties: DuplicateProperty[];
+    span: Span;
 }
 
-export type DatamodelError = string;
+export type FieldArity = "required" | "optional" | "list";
 
-export interface Diagnostics {
-    errors: DatamodelError[];
+export interface Field {
+    name: string;
+    documentation: string | null;
+    fieldType: string;
+    arity: FieldArity;
+    attributes: Attribute[];
+    span: Span;
 }
 
-export type Datasource = { _tag: "db"; value: DatasourceDb };
+export interface Model {
+    name: string;
+    documentation: string | null;
+    fields: Field[];
+    attributes: Attribute[];
+    span: Span;
+}
+
+export interface EnumValue {
+    name: string;
+    documentation: string | null;
+    attributes: Attribute[];
+    span: Span;
+}
+
+export interface Enum {
+    name: string;
+    documentation: string | null;
+    values: EnumValue[];
+    attributes: Attribute[];
+    span: Span;
+}
+
+export interface CompositeType {
+    name: string;
+    documentation: string | null;
+    fields: Field[];
+    span: Span;
+}
+
+export type GeneratorValue = { _tag: "string"; value: string; span: Span } | { _tag: "array"; value: Spanned<string>[]; span: Span } | { _tag: "env"; value: string; span: Span };
+
+export interface Generator {
+    name: string;
+    documentation: string | null;
+    provider: GeneratorValue | null;
+    output: GeneratorValue | null;
+    previewFeatures: Spanned<string>[];
+    binaryTargets: Spanned<string>[];
+    config: Record<string, GeneratorValue>;
+    span: Span;
+}
 
+export type Expression = { _tag: "stringValue"; value: string; span: Span } | { _tag: "numericValue"; value: string; span: Span } | { _tag: "constantValue"; value: string; span: Span } | ({ _tag: "function" } & FunctionCall) | { _tag: "array"; value: Expression[]; span: Span };
+
+export interface FunctionCall {
+    name: string;
+    arguments: Argument[];
+    span: Span;
+}
+
+export interface Argument {
+    name: string | null;
+    value: Expression;
+    span: Span;
+}
+
+export interface Attribute {
+    name: string;
+    arguments: Argument[];
+    span: Span;
+}
+
+export interface Span {
+    start: number;
+    end: number;
+}
+
+export interface Spanned<T> {
+    value: T;
+    span: Span;
+}
+
+export type ErrorCode = "E001_UNSUPPORTED_PROVIDER" | "E002_INVALID_URL" | "E003_UNKNOWN_PROPERTY" | "E004_DUPLICATE_DATASOURCE" | "E005_MULTIPLE_DATASOURCES" | "E006_SYNTAX_ERROR" | "E007_MISSING_PROPERTY" | "E008_INVALID_PROPERTY_VALUE" | "E009_ENV_VAR_NOT_FOUND" | "E010_UNKNOWN_URL_PARAMETER" | "E011_UNSUPPORTED_FEATURE" | "E012_INVALID_RELATION" | "E013_INVALID_REFERENTIAL_ACTION" | "E014_UNKNOWN_TYPE" | "E015_INVALID_NATIVE_TYPE" | "E016_DUPLICATE_NAME" | "E017_RESERVED_NAME" | "E018_INVALID_DEFAULT_VALUE" | "E019_DUPLICATE_PROPERTY";
+
+export type Severity = "error" | "warning";
+
+export interface RelatedSpan {
+    message: string;
+    span: Span;
+}
+
+export interface DatamodelError {
+    severity: "error";
+    code: ErrorCode;
+    message: string;
+    span: Span;
+    relatedSpans: RelatedSpan[];
+}
+
+export interface DatamodelWarning {
+    severity: "warning";
+    code: ErrorCode;
+    message: string;
+    span: Span;
+}
+
+export interface Diagnostics {
+    errors: DatamodelError[];
+    warnings: DatamodelWarning[];
+}
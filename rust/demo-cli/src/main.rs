@@ -1,9 +1,18 @@
 use std::{
   fs::File,
-  io::{BufReader, Read},
+  io::{BufReader, IsTerminal, Read},
 };
 
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
+use schema_parser::ast::schema::SchemaAST;
+use schema_parser::validate::diagnostics::Diagnostics;
+use termcolor::{ColorChoice, StandardStream};
+
+use crate::dotenv::DotEnv;
+use crate::render::DiagnosticsRenderer;
+
+mod dotenv;
+mod render;
 
 #[derive(Parser)]
 #[clap(name = "demo-cli")]
@@ -11,6 +20,32 @@ use clap::{Parser, Subcommand};
 struct Cli {
   #[clap(subcommand)]
   command: Commands,
+
+  /// Whether to color diagnostics. Colors are never used if the NO_COLOR environment variable
+  /// is set.
+  #[clap(long, global = true, value_enum, default_value = "auto")]
+  color: ColorMode,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ColorMode {
+  /// Use colors if diagnostics are printed to a terminal.
+  Auto,
+  Always,
+  Never,
+}
+
+impl ColorMode {
+  /// Decide whether diagnostics printed to stderr should be colored.
+  fn color_choice(self) -> ColorChoice {
+    let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    match self {
+      _ if no_color => ColorChoice::Never,
+      Self::Always => ColorChoice::Always,
+      Self::Auto if std::io::stderr().is_terminal() => ColorChoice::Auto,
+      Self::Auto | Self::Never => ColorChoice::Never,
+    }
+  }
 }
 
 #[derive(Subcommand)]
@@ -41,6 +76,11 @@ struct ParseCmd {
 struct ValidateCmd {
   #[clap(long)]
   schema: std::path::PathBuf,
+
+  /// The .env file to read environment variables from. By default, the .env files next to the
+  /// schema and in the current directory are read, if they exist.
+  #[clap(long)]
+  env_file: Option<std::path::PathBuf>,
 }
 
 #[derive(Parser)]
@@ -50,56 +90,89 @@ struct PanicCmd {
 }
 
 fn read_schema(schema_path: &std::path::Path) -> Result<String, std::io::Error> {
-  let file = File::open(&schema_path)?;
+  let file = File::open(schema_path)?;
   let mut buf_reader = BufReader::new(file);
   let mut schema_contents = String::new();
   buf_reader.read_to_string(&mut schema_contents)?;
   Ok(schema_contents)
 }
 
-fn handle_parse_cmd(cmd: ParseCmd) -> std::io::Result<()> {
-  let schema_contents = read_schema(&cmd.schema);
-  if let Err(e) = schema_contents {
-    eprintln!("Error reading schema file: {}", e);
-    std::process::exit(1);
-  }
+/// Print parser and validation diagnostics to stderr in the same format, with an excerpt of the
+/// schema for each of them.
+fn print_diagnostics(
+  schema: &Schema,
+  color: ColorMode,
+  diagnostics: &Diagnostics,
+) -> std::io::Result<()> {
+  let mut stderr = StandardStream::stderr(color.color_choice());
+  DiagnosticsRenderer::new(&schema.file_name, &schema.contents).render(&mut stderr, diagnostics)
+}
+
+/// A schema file read from disk.
+struct Schema {
+  file_name: String,
+  contents: String,
+}
 
-  let schema_contents = schema_contents.unwrap();
+impl Schema {
+  fn read(schema_path: &std::path::Path) -> Self {
+    match read_schema(schema_path) {
+      Ok(contents) => Self { file_name: schema_path.display().to_string(), contents },
+      Err(e) => {
+        eprintln!("Error reading schema file: {}", e);
+        std::process::exit(1);
+      }
+    }
+  }
+}
 
+fn parse_schema(schema: &Schema, color: ColorMode) -> std::io::Result<SchemaAST> {
   println!("Parsing schema...");
-  let schema = schema_parser::parse_schema(schema_contents);
-  println!("Schema parsed successfully!\n");
+  match schema_parser::parse_schema(schema.contents.clone()) {
+    Ok(ast) => {
+      println!("Schema parsed successfully!\n");
+      Ok(ast)
+    }
+    Err(diagnostics) => {
+      print_diagnostics(schema, color, &diagnostics)?;
+      Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "Schema parsing failed"))
+    }
+  }
+}
 
-  let ast = schema.unwrap();
+fn handle_parse_cmd(cmd: ParseCmd, color: ColorMode) -> std::io::Result<()> {
+  let schema = Schema::read(&cmd.schema);
+  let ast = parse_schema(&schema, color)?;
   println!("{:#?}", ast);
 
   Ok(())
 }
 
-fn handle_validate_cmd(cmd: ValidateCmd) -> std::io::Result<()> {
-  let schema_contents = read_schema(&cmd.schema);
-  if let Err(e) = schema_contents {
-    eprintln!("Error reading schema file: {}", e);
-    std::process::exit(1);
-  }
+fn handle_validate_cmd(cmd: ValidateCmd, color: ColorMode) -> std::io::Result<()> {
+  let schema = Schema::read(&cmd.schema);
+  let ast = parse_schema(&schema, color)?;
 
-  let schema_contents = schema_contents.unwrap();
-
-  println!("Parsing schema...");
-  let schema = schema_parser::parse_schema(schema_contents);
-  println!("Schema parsed successfully!\n");
-
-  let ast = schema.unwrap();
+  let dotenv = match DotEnv::load(&cmd.schema, cmd.env_file.as_deref()) {
+    Ok(dotenv) => dotenv,
+    Err(e) => {
+      eprintln!("{}", e);
+      std::process::exit(1);
+    }
+  };
+  for line in dotenv.report() {
+    println!("{}", line);
+  }
 
   println!("Validating AST...");
 
-  match schema_parser::validate_ast(&ast) {
-    Ok(_) => {
+  match schema_parser::validate_ast_with_env(&ast, &dotenv) {
+    Ok(diagnostics) => {
+      print_diagnostics(&schema, color, &diagnostics)?;
       println!("AST validated successfully!");
       Ok(())
     }
-    Err(e) => {
-      eprintln!("[rust:error]: {:?}", &e);
+    Err(diagnostics) => {
+      print_diagnostics(&schema, color, &diagnostics)?;
       Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "AST validation failed"))
     }
   }
@@ -126,10 +199,10 @@ fn main() -> std::io::Result<()> {
   let cmd = Cli::parse();
 
   match cmd {
-    Cli { command: Commands::Serde } => handle_demo_serde_cmd(),
-    Cli { command: Commands::Tsify } => handle_demo_tsify_cmd(),
-    Cli { command: Commands::Parse(cmd) } => handle_parse_cmd(cmd),
-    Cli { command: Commands::Validate(cmd) } => handle_validate_cmd(cmd),
-    Cli { command: Commands::Panic(cmd) } => handle_panic_cmd(cmd),
+    Cli { command: Commands::Serde, .. } => handle_demo_serde_cmd(),
+    Cli { command: Commands::Tsify, .. } => handle_demo_tsify_cmd(),
+    Cli { command: Commands::Parse(cmd), color } => handle_parse_cmd(cmd, color),
+    Cli { command: Commands::Validate(cmd), color } => handle_validate_cmd(cmd, color),
+    Cli { command: Commands::Panic(cmd), .. } => handle_panic_cmd(cmd),
   }
 }
@@ -0,0 +1,272 @@
+use std::io;
+
+use schema_parser::ast::span::Span;
+use schema_parser::validate::diagnostics::Diagnostics;
+use termcolor::{Color, ColorSpec, WriteColor};
+
+/// Severity of a rendered message, which determines its label and color.
+#[derive(Clone, Copy)]
+enum Severity {
+  Error,
+  Warning,
+  Note,
+}
+
+impl Severity {
+  fn label(self) -> &'static str {
+    match self {
+      Self::Error => "error",
+      Self::Warning => "warning",
+      Self::Note => "note",
+    }
+  }
+
+  fn color(self) -> Color {
+    match self {
+      Self::Error => Color::Red,
+      Self::Warning => Color::Yellow,
+      Self::Note => Color::Green,
+    }
+  }
+}
+
+/// Renders diagnostics similarly to rustc, e.g.:
+///
+/// error[E006_SYNTAX_ERROR]: Unexpected `modle`, did you mean `model`?
+///  --> schema.prisma:7:1
+///   |
+/// 7 | modle User {
+///   | ^^^^^
+///
+/// Related spans are rendered as notes, each with its own source excerpt. The gutters of an error
+/// and its notes have the same width, so that their `|` are aligned.
+pub struct DiagnosticsRenderer<'a> {
+  file_name: &'a str,
+  source: &'a str,
+}
+
+impl<'a> DiagnosticsRenderer<'a> {
+  pub fn new(file_name: &'a str, source: &'a str) -> Self {
+    Self { file_name, source }
+  }
+
+  pub fn render(&self, out: &mut impl WriteColor, diagnostics: &Diagnostics) -> io::Result<()> {
+    for error in diagnostics.errors() {
+      let spans = error.related_spans.iter().map(|related| related.span);
+      let gutter_width = self.gutter_width(spans.chain([error.span]));
+      let title = format!("{}[{}]", Severity::Error.label(), error.code);
+      self.render_message(out, Severity::Error, &title, &error.message, error.span, gutter_width)?;
+      for related in &error.related_spans {
+        let (message, span) = (&related.message, related.span);
+        self.render_message(out, Severity::Note, "note", message, span, gutter_width)?;
+      }
+      writeln!(out)?;
+    }
+
+    for warning in diagnostics.warnings() {
+      let gutter_width = self.gutter_width([warning.span]);
+      let title = format!("{}[{}]", Severity::Warning.label(), warning.code);
+      let (message, span) = (&warning.message, warning.span);
+      self.render_message(out, Severity::Warning, &title, message, span, gutter_width)?;
+      writeln!(out)?;
+    }
+
+    Ok(())
+  }
+
+  /// Width of the gutter, i.e., of the largest line number among the given spans.
+  fn gutter_width(&self, spans: impl IntoIterator<Item = Span>) -> usize {
+    spans
+      .into_iter()
+      .map(|span| span.start_line_column(self.source).line.to_string().len())
+      .max()
+      .unwrap_or(1)
+  }
+
+  fn render_message(
+    &self,
+    out: &mut impl WriteColor,
+    severity: Severity,
+    title: &str,
+    message: &str,
+    span: Span,
+    gutter_width: usize,
+  ) -> io::Result<()> {
+    let start = span.start_line_column(self.source);
+    let end = span.end_line_column(self.source);
+    let line = self.source.lines().nth(start.line - 1).unwrap_or_default();
+    let line_number = format!("{:>gutter_width$}", start.line);
+    let gutter = " ".repeat(gutter_width);
+
+    // header, e.g., "error[E001_UNSUPPORTED_PROVIDER]: The provider ..."
+    out.set_color(ColorSpec::new().set_fg(Some(severity.color())).set_bold(true))?;
+    write!(out, "{title}")?;
+    out.set_color(ColorSpec::new().set_bold(true))?;
+    writeln!(out, ": {message}")?;
+
+    // location, e.g., " --> schema.prisma:3:7"
+    set_gutter_color(out)?;
+    write!(out, "{gutter}--> ")?;
+    out.reset()?;
+    writeln!(out, "{}:{}:{}", self.file_name, start.line, start.column)?;
+
+    // source excerpt
+    set_gutter_color(out)?;
+    writeln!(out, "{gutter} |")?;
+    write!(out, "{line_number} | ")?;
+    out.reset()?;
+    writeln!(out, "{line}")?;
+
+    // carets under the offending range, limited to its first line
+    let line_len = line.chars().count();
+    let caret_end = if end.line == start.line { end.column } else { line_len + 1 };
+    let caret_count = caret_end.saturating_sub(start.column).max(1);
+    let padding: String = line
+      .chars()
+      .take(start.column - 1)
+      .map(|c| if c == '\t' { '\t' } else { ' ' })
+      .collect();
+
+    set_gutter_color(out)?;
+    write!(out, "{gutter} | ")?;
+    out.set_color(ColorSpec::new().set_fg(Some(severity.color())).set_bold(true))?;
+    writeln!(out, "{padding}{}", "^".repeat(caret_count))?;
+    out.reset()?;
+
+    Ok(())
+  }
+}
+
+fn set_gutter_color(out: &mut impl WriteColor) -> io::Result<()> {
+  out.set_color(ColorSpec::new().set_fg(Some(Color::Blue)).set_bold(true))
+}
+
+#[cfg(test)]
+mod test {
+  use schema_parser::validate::diagnostics::{DatamodelError, DatamodelWarning, ErrorCode};
+  use termcolor::Buffer;
+
+  use super::*;
+
+  fn render(source: &str, diagnostics: &Diagnostics) -> String {
+    let mut buffer = Buffer::no_color();
+    DiagnosticsRenderer::new("schema.prisma", source).render(&mut buffer, diagnostics).unwrap();
+    String::from_utf8(buffer.into_inner()).unwrap()
+  }
+
+  fn span_of(source: &str, text: &str) -> Span {
+    let start = source.find(text).unwrap();
+    Span::new(start, start + text.len())
+  }
+
+  #[test]
+  fn test_single_line_span() {
+    let source = "datasource db {\n  provider = \"mysql\"\n}\n";
+    let error = DatamodelError::new(
+      ErrorCode::UnsupportedProvider,
+      "The provider \"mysql\" is not yet supported.",
+      span_of(source, "\"mysql\""),
+    );
+    assert_eq!(
+      render(source, &Diagnostics::from(error)),
+      concat!(
+        "error[E001_UNSUPPORTED_PROVIDER]: The provider \"mysql\" is not yet supported.\n",
+        " --> schema.prisma:2:14\n",
+        "  |\n",
+        "2 |   provider = \"mysql\"\n",
+        "  |              ^^^^^^^\n",
+        "\n",
+      )
+    );
+  }
+
+  #[test]
+  fn test_multi_line_span() {
+    let source = "model User {\n  id Int @id\n}\n";
+    let error = DatamodelError::new(
+      ErrorCode::DuplicateName,
+      "The model \"User\" cannot be defined more than once.",
+      span_of(source, "model User {\n  id Int @id\n}"),
+    );
+    // carets stop at the end of the first line
+    assert_eq!(
+      render(source, &Diagnostics::from(error)),
+      concat!(
+        "error[E016_DUPLICATE_NAME]: The model \"User\" cannot be defined more than once.\n",
+        " --> schema.prisma:1:1\n",
+        "  |\n",
+        "1 | model User {\n",
+        "  | ^^^^^^^^^^^^\n",
+        "\n",
+      )
+    );
+  }
+
+  #[test]
+  fn test_span_at_end_of_file() {
+    let source = "model User {\n  id Int @id\n";
+    let error = DatamodelError::new(
+      ErrorCode::SyntaxError,
+      "Unexpected end of input, expected `}`.",
+      Span::new(source.len(), source.len()),
+    );
+    assert_eq!(
+      render(source, &Diagnostics::from(error)),
+      concat!(
+        "error[E006_SYNTAX_ERROR]: Unexpected end of input, expected `}`.\n",
+        " --> schema.prisma:3:1\n",
+        "  |\n",
+        "3 | \n",
+        "  | ^\n",
+        "\n",
+      )
+    );
+  }
+
+  #[test]
+  fn test_notes_in_the_same_file() {
+    let mut source = String::from("generator client {\n  provider = \"prisma-client-js\"\n}\n");
+    source.push_str(&"\n".repeat(8));
+    source.push_str("generator client {\n  provider = \"prisma-client-js\"\n}\n");
+    let first = source.find("generator").unwrap();
+    let second = source.rfind("generator").unwrap();
+
+    let error = DatamodelError::new(
+      ErrorCode::DuplicateName,
+      "The generator \"client\" cannot be defined more than once.",
+      Span::new(second, second + "generator client".len()),
+    )
+    .with_related_span("first defined here", Span::new(first, first + "generator client".len()));
+    let mut diagnostics = Diagnostics::from(error);
+    diagnostics.push_warning(DatamodelWarning::new(
+      ErrorCode::UnknownUrlParameter,
+      "The parameter \"foo\" is not supported in \"postgres\" URLs.",
+      Span::new(first, first + "generator".len()),
+    ));
+
+    // the error and its note share the same gutter width, the warning has its own
+    assert_eq!(
+      render(&source, &diagnostics),
+      concat!(
+        "error[E016_DUPLICATE_NAME]: The generator \"client\" cannot be defined more than once.\n",
+        "  --> schema.prisma:12:1\n",
+        "   |\n",
+        "12 | generator client {\n",
+        "   | ^^^^^^^^^^^^^^^^\n",
+        "note: first defined here\n",
+        "  --> schema.prisma:1:1\n",
+        "   |\n",
+        " 1 | generator client {\n",
+        "   | ^^^^^^^^^^^^^^^^\n",
+        "\n",
+        "warning[E010_UNKNOWN_URL_PARAMETER]: ",
+        "The parameter \"foo\" is not supported in \"postgres\" URLs.\n",
+        " --> schema.prisma:1:1\n",
+        "  |\n",
+        "1 | generator client {\n",
+        "  | ^^^^^^^^^\n",
+        "\n",
+      )
+    );
+  }
+}
@@ -1,4 +1,6 @@
 use schema_parser::ast::schema::SchemaAST;
+use schema_parser::validate::diagnostics::Diagnostics;
+use schema_parser::validate::env::EnvProvider;
 use serde::Serialize;
 use wasm_bindgen::prelude::*;
 
@@ -10,13 +12,39 @@ pub fn example_schema_wasm() -> Result<SchemaAST, JsError> {
 
 #[wasm_bindgen(js_name = parseSchema)]
 pub fn parse_schema_wasm(input: String) -> Result<SchemaAST, JsError> {
-  let ast_result: Result<SchemaAST, String> = schema_parser::parse_schema(input);
+  let ast_result: Result<SchemaAST, Diagnostics> = schema_parser::parse_schema(input);
   ast_result.map_err(|err| to_js_error(&err))
 }
 
+#[wasm_bindgen]
+extern "C" {
+  /// Environment variables referenced via env("...") in a schema, e.g., `process.env`,
+  /// or a function that returns the value of a given variable.
+  #[wasm_bindgen(
+    typescript_type = "Record<string, string | undefined> | ((name: string) => string | undefined)"
+  )]
+  pub type Env;
+}
+
+/// Reads environment variables from a JS object or callback.
+/// Values that aren't strings are treated as missing.
+struct JsEnv(Option<Env>);
+
+impl EnvProvider for JsEnv {
+  fn var(&self, name: &str) -> Option<String> {
+    let env: &JsValue = self.0.as_ref()?;
+    let name = JsValue::from_str(name);
+    let value = match env.dyn_ref::<js_sys::Function>() {
+      Some(callback) => callback.call1(&JsValue::NULL, &name),
+      None => js_sys::Reflect::get(env, &name),
+    };
+    value.ok()?.as_string()
+  }
+}
+
 #[wasm_bindgen(js_name = validateAST)]
-pub fn validate_schema_wasm(ast: SchemaAST) -> Result<(), JsError> {
-  schema_parser::validate_ast(&ast).map_err(|err| to_js_error(&err))
+pub fn validate_schema_wasm(ast: SchemaAST, env: Option<Env>) -> Result<Diagnostics, JsError> {
+  schema_parser::validate_ast_with_env(&ast, &JsEnv(env)).map_err(|err| to_js_error(&err))
 }
 
 fn to_js_error<T>(err: &T) -> JsError
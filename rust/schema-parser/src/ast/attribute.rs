@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+use super::span::Span;
+
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// Value of an attribute argument, e.g., "user_id", 42, true, now(), or [a, b].
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "_tag")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub enum Expression {
+  /// A quoted string, e.g., "user_id".
+  StringValue { value: String, span: Span },
+
+  /// A number, kept verbatim to avoid losing precision, e.g., 42 or -3.14.
+  NumericValue { value: String, span: Span },
+
+  /// An unquoted identifier, e.g., true, Desc, or an enum value.
+  ConstantValue { value: String, span: Span },
+
+  /// A function call, e.g., autoincrement() or dbgenerated("gen_random_uuid()").
+  Function(FunctionCall),
+
+  /// A list of expressions, e.g., [id, email].
+  Array { value: Vec<Expression>, span: Span },
+}
+
+impl Expression {
+  pub fn span(&self) -> Span {
+    match self {
+      Self::StringValue { span, .. }
+      | Self::NumericValue { span, .. }
+      | Self::ConstantValue { span, .. }
+      | Self::Array { span, .. } => *span,
+      Self::Function(function_call) => function_call.span,
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub struct FunctionCall {
+  pub name: String,
+  pub arguments: Vec<Argument>,
+  pub span: Span,
+}
+
+/// An argument of an attribute or of a function call.
+/// Named arguments look like `map: "user_id"`, positional arguments like `"user_id"`.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub struct Argument {
+  pub name: Option<String>,
+  pub value: Expression,
+  pub span: Span,
+}
+
+/// An attribute, e.g., @id, @default(autoincrement()) or @map("user_id").
+/// The name is stored without the leading "@".
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub struct Attribute {
+  pub name: String,
+  pub arguments: Vec<Argument>,
+
+  /// Span of the whole attribute, including the leading "@" or "@@".
+  pub span: Span,
+}
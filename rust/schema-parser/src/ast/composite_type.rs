@@ -0,0 +1,25 @@
+use super::model::Field;
+use super::span::Span;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// A composite type, i.e., a `type` block, which describes an embedded document.
+/// Composite types have fields, like models, but no block attributes.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub struct CompositeType {
+  pub name: String,
+  pub documentation: Option<String>,
+
+  /// Fields of the composite type, in the same order they are defined in the schema.
+  pub fields: Vec<Field>,
+
+  /// Span of the composite type, from the "type" keyword to the closing brace.
+  pub span: Span,
+}
@@ -1,4 +1,5 @@
 use super::datasource_db::DatasourceDb;
+use super::span::Span;
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "wasm")]
@@ -13,3 +14,18 @@ use wasm_bindgen::prelude::*;
 pub enum Datasource {
   Db(DatasourceDb),
 }
+
+impl Datasource {
+  /// Name of the datasource block, e.g., "db" in `datasource db { ... }`.
+  pub fn name(&self) -> &str {
+    match self {
+      Self::Db(datasource) => &datasource.name,
+    }
+  }
+
+  pub fn span(&self) -> Span {
+    match self {
+      Self::Db(datasource) => datasource.span,
+    }
+  }
+}
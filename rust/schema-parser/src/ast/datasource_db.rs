@@ -2,13 +2,15 @@ use std::fmt;
 
 use serde::{Deserialize, Serialize};
 
+use super::span::{Span, Spanned};
+use crate::redact::redact;
+
 #[cfg(feature = "wasm")]
 use tsify::Tsify;
 
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
 
-// Clone is needed for parser macros in schema_parser::parser::parser.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 #[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
@@ -22,41 +24,134 @@ pub enum Provider {
   MongoDb,
 }
 
-impl From<&str> for Provider {
-  fn from(provider: &str) -> Self {
-    match provider {
-      "postgres" => Self::Postgres,
-      "cockroachdb" => Self::CockroachDb,
-      "mysql" => Self::MySQL,
-      "mariadb" => Self::MariaDb,
-      "sqlserver" => Self::SQLServer,
-      "sqlite" => Self::SQLite,
-      "mongodb" => Self::MongoDb,
-      _ => panic!("Unknown provider: {}", provider),
+impl fmt::Display for Provider {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "\"{}\"", self.name())
+  }
+}
+
+#[derive(PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "_tag")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub enum Url {
+  Static { value: String, span: Span },
+  Env { value: String, span: Span },
+}
+
+impl Url {
+  pub fn span(&self) -> Span {
+    match self {
+      Self::Static { span, .. } | Self::Env { span, .. } => *span,
     }
   }
 }
 
-impl fmt::Display for Provider {
+/// Static URLs are redacted, as they may contain passwords.
+impl fmt::Display for Url {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let provider_as_str = serde_json::to_string(self).unwrap();
-    write!(f, "{}", provider_as_str)
+    match self {
+      Self::Static { value, .. } => write!(f, "{}", redact(value)),
+      Self::Env { value, .. } => write!(f, "env(\"{}\")", value),
+    }
   }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase", tag = "_tag", content = "value")]
+/// Static URLs are redacted, as they may contain passwords.
+impl fmt::Debug for Url {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Self::Static { value, span } => {
+        f.debug_struct("Static").field("value", &redact(value)).field("span", span).finish()
+      }
+      Self::Env { value, span } => {
+        f.debug_struct("Env").field("value", value).field("span", span).finish()
+      }
+    }
+  }
+}
+
+/// Where referential integrity is enforced, e.g., relationMode = "prisma".
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
-pub enum Url {
-  Static(String),
-  Env(String),
+pub enum RelationMode {
+  /// Foreign keys are enforced by the database.
+  ForeignKeys,
+
+  /// Relations are emulated by Prisma.
+  Prisma,
+}
+
+impl fmt::Display for RelationMode {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Self::ForeignKeys => write!(f, "\"foreignKeys\""),
+      Self::Prisma => write!(f, "\"prisma\""),
+    }
+  }
+}
+
+/// A database extension, e.g., pg_trgm or postgis(version: "3.3").
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub struct Extension {
+  pub name: String,
+  pub version: Option<String>,
+  pub schema: Option<String>,
+  pub map: Option<String>,
+  pub span: Span,
+}
+
+/// A property defined more than once in a datasource block, e.g., a second `url = "..."`.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub struct DuplicateProperty {
+  pub name: String,
+
+  /// Span of the duplicate definition, from the property name to its value.
+  pub span: Span,
+
+  /// Span of the first definition, which is the one that's kept.
+  pub first_span: Span,
+}
+
+/// A property that isn't supported in a datasource block, e.g., `foo = "bar"`.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub struct UnknownProperty {
+  pub name: String,
+  pub span: Span,
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
 pub struct DatasourceDb {
-  pub provider: Provider,
-  pub url: Url,
+  /// Name of the datasource block, e.g., "db" in `datasource db { ... }`.
+  pub name: String,
+  pub documentation: Option<String>,
+
+  /// The provider, e.g., "postgres" in `provider = "postgres"`.
+  /// It's None if the property is missing, which is reported by the validator, like a missing url.
+  pub provider: Option<Spanned<Provider>>,
+  pub url: Option<Url>,
   pub shadow_database_url: Option<Url>,
+  pub direct_url: Option<Url>,
+  pub relation_mode: Option<Spanned<RelationMode>>,
+  pub extensions: Vec<Extension>,
+  pub schemas: Vec<Spanned<String>>,
+
+  /// Properties that aren't supported in a datasource block.
+  /// These are reported by the validator.
+  pub unknown_properties: Vec<UnknownProperty>,
+
+  /// Properties defined more than once, whose first definition is kept.
+  /// These are reported by the validator.
+  pub duplicate_properties: Vec<DuplicateProperty>,
+
+  /// Span of the datasource, from the "datasource" keyword to the closing brace.
+  pub span: Span,
 }
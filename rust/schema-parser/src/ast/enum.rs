@@ -0,0 +1,36 @@
+use super::attribute::Attribute;
+use super::span::Span;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub struct EnumValue {
+  pub name: String,
+  pub documentation: Option<String>,
+  pub attributes: Vec<Attribute>,
+  pub span: Span,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub struct Enum {
+  pub name: String,
+  pub documentation: Option<String>,
+
+  /// Values of the enum, in the same order they are defined in the schema.
+  pub values: Vec<EnumValue>,
+
+  /// Block attributes of the enum (e.g., @@map), in the same order they are defined in the schema.
+  pub attributes: Vec<Attribute>,
+
+  /// Span of the enum, from the "enum" keyword to the closing brace.
+  pub span: Span,
+}
@@ -0,0 +1,50 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::span::{Span, Spanned};
+
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// Value of a generator property, e.g.:
+/// - "prisma-client-js" (String)
+/// - ["native", "debian-openssl-3.0.x"] (Array)
+/// - env("GENERATOR_OUTPUT") (Env)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "_tag")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub enum GeneratorValue {
+  String { value: String, span: Span },
+  Array { value: Vec<Spanned<String>>, span: Span },
+  Env { value: String, span: Span },
+}
+
+impl GeneratorValue {
+  pub fn span(&self) -> Span {
+    match self {
+      Self::String { span, .. } | Self::Array { span, .. } | Self::Env { span, .. } => *span,
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub struct Generator {
+  pub name: String,
+  pub documentation: Option<String>,
+  pub provider: Option<GeneratorValue>,
+  pub output: Option<GeneratorValue>,
+  pub preview_features: Vec<Spanned<String>>,
+  pub binary_targets: Vec<Spanned<String>>,
+
+  /// Any other property of the generator block, e.g., `engineType = "binary"`.
+  pub config: BTreeMap<String, GeneratorValue>,
+
+  /// Span of the generator, from the "generator" keyword to the closing brace.
+  pub span: Span,
+}
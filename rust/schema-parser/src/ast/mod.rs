@@ -1,3 +1,9 @@
+pub mod attribute;
+pub mod composite_type;
 pub mod datasource;
 pub mod datasource_db;
+pub mod r#enum;
+pub mod generator;
+pub mod model;
 pub mod schema;
+pub mod span;
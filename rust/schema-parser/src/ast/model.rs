@@ -0,0 +1,152 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::attribute::Attribute;
+use super::span::Span;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// Arity of a model field, i.e., whether it's required (`String`),
+/// optional (`String?`), or a list (`String[]`).
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub enum FieldArity {
+  Required,
+  Optional,
+  List,
+}
+
+/// Built-in scalar types of fields.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ScalarType {
+  String,
+  Boolean,
+  Int,
+  BigInt,
+  Float,
+  Decimal,
+  DateTime,
+  Json,
+  Bytes,
+}
+
+impl ScalarType {
+  pub const ALL: &'static [Self] = &[
+    Self::String,
+    Self::Boolean,
+    Self::Int,
+    Self::BigInt,
+    Self::Float,
+    Self::Decimal,
+    Self::DateTime,
+    Self::Json,
+    Self::Bytes,
+  ];
+
+  /// Name of the scalar type in the schema, e.g., "DateTime".
+  pub fn name(&self) -> &'static str {
+    match self {
+      Self::String => "String",
+      Self::Boolean => "Boolean",
+      Self::Int => "Int",
+      Self::BigInt => "BigInt",
+      Self::Float => "Float",
+      Self::Decimal => "Decimal",
+      Self::DateTime => "DateTime",
+      Self::Json => "Json",
+      Self::Bytes => "Bytes",
+    }
+  }
+}
+
+impl fmt::Display for ScalarType {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.name())
+  }
+}
+
+impl FromStr for ScalarType {
+  type Err = ();
+
+  fn from_str(name: &str) -> Result<Self, Self::Err> {
+    Self::ALL.iter().find(|scalar_type| scalar_type.name() == name).copied().ok_or(())
+  }
+}
+
+/// What happens to a record when the record it refers to is deleted or updated,
+/// e.g., `onDelete: Cascade`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ReferentialAction {
+  Cascade,
+  Restrict,
+  NoAction,
+  SetNull,
+  SetDefault,
+}
+
+impl ReferentialAction {
+  pub const ALL: &'static [Self] =
+    &[Self::Cascade, Self::Restrict, Self::NoAction, Self::SetNull, Self::SetDefault];
+
+  pub fn name(&self) -> &'static str {
+    match self {
+      Self::Cascade => "Cascade",
+      Self::Restrict => "Restrict",
+      Self::NoAction => "NoAction",
+      Self::SetNull => "SetNull",
+      Self::SetDefault => "SetDefault",
+    }
+  }
+}
+
+impl fmt::Display for ReferentialAction {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.name())
+  }
+}
+
+impl FromStr for ReferentialAction {
+  type Err = ();
+
+  fn from_str(name: &str) -> Result<Self, Self::Err> {
+    Self::ALL.iter().find(|action| action.name() == name).copied().ok_or(())
+  }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub struct Field {
+  pub name: String,
+  pub documentation: Option<String>,
+  pub field_type: String,
+  pub arity: FieldArity,
+  pub attributes: Vec<Attribute>,
+
+  /// Span of the field, from its name to its last attribute. Documentation isn't included.
+  pub span: Span,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub struct Model {
+  pub name: String,
+  pub documentation: Option<String>,
+
+  /// Fields of the model, in the same order they are defined in the schema.
+  pub fields: Vec<Field>,
+
+  /// Block attributes of the model (e.g., @@id, @@index, @@map), in the same order
+  /// they are defined in the schema. Names are stored without the leading "@@".
+  pub attributes: Vec<Attribute>,
+
+  /// Span of the model, from the "model" keyword to the closing brace.
+  pub span: Span,
+}
@@ -1,4 +1,8 @@
+use super::composite_type::CompositeType;
 use super::datasource::Datasource;
+use super::generator::Generator;
+use super::model::Model;
+use super::r#enum::Enum;
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "wasm")]
@@ -13,6 +17,10 @@ use wasm_bindgen::prelude::*;
 #[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
 pub enum SchemaASTBlock {
   Datasource(Datasource),
+  Model(Model),
+  Enum(Enum),
+  CompositeType(CompositeType),
+  Generator(Generator),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -20,4 +28,8 @@ pub enum SchemaASTBlock {
 #[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
 pub struct SchemaAST {
   pub datasources: Vec<Datasource>,
+  pub models: Vec<Model>,
+  pub enums: Vec<Enum>,
+  pub composite_types: Vec<CompositeType>,
+  pub generators: Vec<Generator>,
 }
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// Location of an AST node in the schema source, as byte offsets.
+/// `start` is inclusive, `end` is exclusive.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub struct Span {
+  pub start: usize,
+  pub end: usize,
+}
+
+impl Span {
+  pub fn new(start: usize, end: usize) -> Self {
+    Self { start, end }
+  }
+
+  /// Returns true if the given byte offset is within this span.
+  pub fn contains(&self, offset: usize) -> bool {
+    self.start <= offset && offset < self.end
+  }
+
+  /// Convert the start of this span to a line and column in the given schema source.
+  pub fn start_line_column(&self, source: &str) -> LineColumn {
+    LineColumn::from_offset(source, self.start)
+  }
+
+  /// Convert the end of this span to a line and column in the given schema source.
+  pub fn end_line_column(&self, source: &str) -> LineColumn {
+    LineColumn::from_offset(source, self.end)
+  }
+}
+
+/// A value together with its span, for values that can't carry a span themselves, e.g., the
+/// strings of `schemas = ["public", "auth"]` or the relation mode of a datasource.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+pub struct Spanned<T> {
+  pub value: T,
+  pub span: Span,
+}
+
+impl<T> Spanned<T> {
+  pub fn new(value: T, span: Span) -> Self {
+    Self { value, span }
+  }
+}
+
+#[cfg(test)]
+impl Span {
+  /// Span of the first occurrence of text in the given source, handy to write expected spans.
+  pub(crate) fn of(source: &str, text: &str) -> Self {
+    let start = source.find(text).unwrap_or_else(|| panic!("{text:?} not found in source"));
+    Self::new(start, start + text.len())
+  }
+}
+
+/// Human-readable position in the schema source.
+/// Both line and column are 1-based, and columns are counted in characters, not in bytes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct LineColumn {
+  pub line: usize,
+  pub column: usize,
+}
+
+impl LineColumn {
+  /// Convert a byte offset to a line and column in the given schema source.
+  /// Offsets past the end of the source are clamped to the end of the source.
+  pub fn from_offset(source: &str, offset: usize) -> Self {
+    let mut offset = offset.min(source.len());
+    while !source.is_char_boundary(offset) {
+      offset -= 1;
+    }
+
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    let column = before[line_start..].chars().count() + 1;
+
+    Self { line, column }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_line_column() {
+    let source = "model User {\n  name Straße\n}\n";
+
+    assert_eq!(LineColumn::from_offset(source, 0), LineColumn { line: 1, column: 1 });
+    assert_eq!(LineColumn::from_offset(source, 6), LineColumn { line: 1, column: 7 });
+    assert_eq!(LineColumn::from_offset(source, 13), LineColumn { line: 2, column: 1 });
+
+    // "ß" takes 2 bytes, but it's a single column
+    let offset = source.find('}').unwrap() - 1;
+    assert_eq!(LineColumn::from_offset(source, offset), LineColumn { line: 2, column: 14 });
+
+    assert_eq!(LineColumn::from_offset(source, 1000), LineColumn { line: 4, column: 1 });
+  }
+
+  #[test]
+  fn test_span_line_column() {
+    let source = "model User {\n  id Int\n}";
+    let span = Span::new(15, 21);
+
+    assert_eq!(&source[span.start..span.end], "id Int");
+    assert_eq!(span.start_line_column(source), LineColumn { line: 2, column: 3 });
+    assert_eq!(span.end_line_column(source), LineColumn { line: 2, column: 9 });
+  }
+}
@@ -1,28 +1,65 @@
-use validate::{diagnostics, validator};
+use validate::diagnostics::Diagnostics;
+use validate::env::{EnvProvider, ProcessEnv};
+use validate::db::ParserDatabase;
+use validate::{defaults, diagnostics, names, native_types, relations, validator};
 
 use crate::ast::datasource::Datasource;
 use crate::ast::datasource_db::{DatasourceDb, Provider, Url};
 use crate::ast::schema::SchemaAST;
-use crate::parse::parser::Parser;
+use crate::ast::span::{Span, Spanned};
+use crate::parse::parser::Input;
+use crate::providers::PROVIDERS;
 
 pub mod ast;
 pub mod parse;
 mod parseutil;
+pub mod providers;
+pub mod redact;
 pub mod validate;
 
-// Given a schema file input, parse it and return a SchemaAST, or a parser error.
-pub fn parse_schema(input: String) -> Result<SchemaAST, String> {
-  let (_, schema_ast) = SchemaAST::parse(&input).map_err(|e| format!("{:?}", e))?;
-  Ok(schema_ast)
+// Given a schema file input, parse it and return a SchemaAST, or the parser errors as diagnostics,
+// in the same format used by validate_ast. The whole input must be parsed: anything that isn't
+// a block, a comment or whitespace is an error.
+pub fn parse_schema(input: String) -> Result<SchemaAST, Diagnostics> {
+  let (schema_ast, mut diagnostics) = parse_schema_recovering(input);
+  diagnostics.to_result().map(|_| schema_ast)
+}
+
+// Given a schema file input, parse as much of it as possible, skipping the blocks with syntax
+// errors. Return the blocks that could be parsed, together with every syntax error found.
+pub fn parse_schema_recovering(input: String) -> (SchemaAST, Diagnostics) {
+  let (schema_ast, errors) = SchemaAST::parse_recovering(Input::new(&input));
+  let mut diagnostics = Diagnostics::new();
+  for error in errors {
+    diagnostics.push_error(error);
+  }
+  (schema_ast, diagnostics)
 }
 
 // Given a parsed schema AST, validate it and return a potential list of validation errors.
-// This function may panic in the following cases:
-// - a datasource is defined with an environment variable URL
-pub fn validate_ast(ast: &SchemaAST) -> Result<(), diagnostics::Diagnostics> {
+// Without errors, the diagnostics are returned on success, as they may contain warnings.
+// Environment variables referenced via env("...") are read from the current process.
+pub fn validate_ast(ast: &SchemaAST) -> Result<Diagnostics, Diagnostics> {
+  validate_ast_with_env(ast, &ProcessEnv)
+}
+
+// Like validate_ast, but environment variables referenced via env("...") are read from the given
+// provider, e.g., a .env file, or a JS object in Wasm.
+pub fn validate_ast_with_env(
+  ast: &SchemaAST,
+  env: &dyn EnvProvider,
+) -> Result<Diagnostics, Diagnostics> {
   let mut diagnostics = diagnostics::Diagnostics::default();
-  let supported_providers: Vec<Provider> = vec![Provider::SQLite, Provider::Postgres];
-  validator::validate_configuration(&ast, &supported_providers, &mut diagnostics);
+  let supported_providers: Vec<Provider> =
+    PROVIDERS.iter().filter(|info| info.supported).map(|info| info.provider.clone()).collect();
+  validator::validate_configuration(ast, &supported_providers, env, &mut diagnostics);
+  names::validate_names(ast, &mut diagnostics);
+
+  let db = ParserDatabase::new(ast, &mut diagnostics);
+  validator::validate_capabilities(&db, &mut diagnostics);
+  native_types::validate_native_types(&db, &mut diagnostics);
+  defaults::validate_defaults(&db, &mut diagnostics);
+  relations::validate_relations(&db, &mut diagnostics);
 
   diagnostics.to_result()
 }
@@ -31,9 +68,22 @@ pub fn validate_ast(ast: &SchemaAST) -> Result<(), diagnostics::Diagnostics> {
 pub fn example_schema() -> SchemaAST {
   SchemaAST {
     datasources: vec![Datasource::Db(DatasourceDb {
-      provider: Provider::Postgres,
-      url: Url::Env(String::from("DATABASE_URL")),
+      name: String::from("db"),
+      documentation: None,
+      provider: Some(Spanned::new(Provider::Postgres, Span::default())),
+      url: Some(Url::Env { value: String::from("DATABASE_URL"), span: Span::default() }),
       shadow_database_url: None,
+      direct_url: None,
+      relation_mode: None,
+      extensions: vec![],
+      schemas: vec![],
+      unknown_properties: vec![],
+      duplicate_properties: vec![],
+      span: Span::default(),
     })],
+    models: vec![],
+    enums: vec![],
+    composite_types: vec![],
+    generators: vec![],
   }
 }
@@ -0,0 +1,375 @@
+use crate::ast::attribute::{Argument, Attribute, Expression, FunctionCall};
+use crate::parseutil::{parse_identifier, parse_string_quoted, spanned, ws};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{char, digit1, one_of};
+use nom::combinator::{cut, map, opt, recognize};
+use nom::error::context;
+use nom::multi::separated_list0;
+use nom::sequence::{pair, preceded, terminated, tuple};
+
+use super::parser::{IResult, Input, Parser};
+
+/// Parse a comma-separated list of arguments, wrapped in parentheses, e.g.,
+/// (fields: [id], map: "user_id").
+fn parse_arguments(input: Input) -> IResult<Vec<Argument>> {
+  preceded(
+    ws(char('(')),
+    cut(terminated(
+      separated_list0(ws(char(',')), ws(Argument::parse)),
+      terminated(opt(ws(char(','))), char(')')),
+    )),
+  )(input)
+}
+
+impl Expression {
+  /// Parse a number, with an optional fraction and exponent, e.g., -3.14 or 1e3.
+  fn parse_numeric(input: Input) -> IResult<Self> {
+    let exponent = tuple((one_of("eE"), opt(one_of("+-")), digit1));
+    map(
+      spanned(recognize(tuple((
+        opt(char('-')),
+        digit1,
+        opt(pair(char('.'), digit1)),
+        opt(exponent),
+      )))),
+      |(n, span)| Self::NumericValue { value: (*n.fragment()).to_owned(), span },
+    )(input)
+  }
+
+  fn parse_array(input: Input) -> IResult<Self> {
+    map(
+      spanned(preceded(
+        ws(char('[')),
+        cut(terminated(
+          separated_list0(ws(char(',')), ws(Self::parse)),
+          terminated(opt(ws(char(','))), char(']')),
+        )),
+      )),
+      |(value, span)| Self::Array { value, span },
+    )(input)
+  }
+}
+
+impl Parser for Expression {
+  /// Parse an expression, e.g.:
+  /// - "user_id" (StringValue)
+  /// - 42 (NumericValue)
+  /// - true (ConstantValue)
+  /// - uuid() (Function)
+  /// - [id, email] (Array)
+  ///
+  /// It expects no whitespace prefix.
+  fn parse(input: Input) -> IResult<Self> {
+    context(
+      "a value",
+      alt((
+        map(spanned(parse_string_quoted('"')), |(value, span)| Self::StringValue { value, span }),
+        Self::parse_numeric,
+        Self::parse_array,
+        map(FunctionCall::parse, Self::Function),
+        map(spanned(parse_identifier), |(constant, span)| Self::ConstantValue {
+          value: constant.to_owned(),
+          span,
+        }),
+      )),
+    )(input)
+  }
+}
+
+impl Parser for FunctionCall {
+  /// Parse a function call, e.g., autoincrement() or dbgenerated("gen_random_uuid()").
+  /// It expects no whitespace prefix.
+  fn parse(input: Input) -> IResult<Self> {
+    spanned(pair(parse_identifier, parse_arguments))(input).map(
+      |(rest, ((name, arguments), span))| (rest, Self { name: name.to_owned(), arguments, span }),
+    )
+  }
+}
+
+impl Parser for Argument {
+  /// Parse a named argument, e.g., `map: "user_id"`, or a positional one, e.g., `"user_id"`.
+  /// It expects no whitespace prefix.
+  fn parse(input: Input) -> IResult<Self> {
+    spanned(pair(opt(terminated(parse_identifier, ws(char(':')))), Expression::parse))(input).map(
+      |(rest, ((name, value), span))| (rest, Self { name: name.map(str::to_owned), value, span }),
+    )
+  }
+}
+
+impl Attribute {
+  /// Parse a block attribute, e.g., @@id([firstName, lastName]) or @@map("users").
+  /// It expects no whitespace prefix.
+  pub fn parse_block(input: Input) -> IResult<Self> {
+    spanned(preceded(tag("@@"), pair(parse_identifier, opt(parse_arguments))))(input).map(
+      |(rest, ((name, arguments), span))| {
+        (rest, Self { name: name.to_owned(), arguments: arguments.unwrap_or_default(), span })
+      },
+    )
+  }
+}
+
+/// Parse the name of a field attribute, optionally prefixed by the name of a datasource,
+/// e.g., "id" or "db.VarChar" for native type attributes.
+fn parse_attribute_name<'a>(input: Input<'a>) -> IResult<'a, &'a str> {
+  map(recognize(pair(parse_identifier, opt(pair(char('.'), parse_identifier)))), |name: Input| {
+    *name.fragment()
+  })(input)
+}
+
+impl Parser for Attribute {
+  /// Parse a field attribute, e.g., @id, @default(now()), @map("user_id") or @db.VarChar(255).
+  /// It expects no whitespace prefix.
+  fn parse(input: Input) -> IResult<Self> {
+    spanned(preceded(char('@'), pair(parse_attribute_name, opt(parse_arguments))))(input).map(
+      |(rest, ((name, arguments), span))| {
+        (rest, Self { name: name.to_owned(), arguments: arguments.unwrap_or_default(), span })
+      },
+    )
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::ast::span::Span;
+
+  fn string(value: &str, start: usize) -> Expression {
+    let span = Span::new(start, start + value.len() + 2);
+    Expression::StringValue { value: String::from(value), span }
+  }
+
+  fn constant(value: &str, start: usize) -> Expression {
+    let span = Span::new(start, start + value.len());
+    Expression::ConstantValue { value: String::from(value), span }
+  }
+
+  fn function(name: &str, arguments: Vec<Argument>, span: Span) -> Expression {
+    Expression::Function(FunctionCall { name: String::from(name), arguments, span })
+  }
+
+  fn positional(value: Expression) -> Argument {
+    Argument { name: None, span: value.span(), value }
+  }
+
+  macro_rules! test_default_function {
+    ($s:expr) => {
+      paste::item! {
+        #[test]
+        fn [< test_default_ $s >] () {
+          let schema = format!("@default({}())", &$s);
+          let (remaining_input, attribute) = Attribute::parse(schema.as_str().into()).unwrap();
+          assert!(remaining_input.is_empty());
+          assert_eq!(
+            attribute,
+            Attribute {
+              name: String::from("default"),
+              arguments: vec![positional(function($s, vec![], Span::new(9, schema.len() - 1)))],
+              span: Span::new(0, schema.len()),
+            }
+          );
+        }
+      }
+    };
+  }
+
+  test_default_function!("autoincrement");
+  test_default_function!("now");
+  test_default_function!("uuid");
+  test_default_function!("cuid");
+
+  #[test]
+  fn test_expression() {
+    let (remaining_input, expression) = Expression::parse(r#""user_id""#.into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(expression, string("user_id", 0));
+
+    let (remaining_input, expression) = Expression::parse("-3.14".into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(
+      expression,
+      Expression::NumericValue { value: String::from("-3.14"), span: Span::new(0, 5) }
+    );
+
+    let (remaining_input, expression) = Expression::parse("2.5E-3".into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(
+      expression,
+      Expression::NumericValue { value: String::from("2.5E-3"), span: Span::new(0, 6) }
+    );
+
+    let (remaining_input, expression) = Expression::parse("true".into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(expression, constant("true", 0));
+
+    let (remaining_input, expression) = Expression::parse("[ id, email ]".into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(
+      expression,
+      Expression::Array {
+        value: vec![constant("id", 2), constant("email", 6)],
+        span: Span::new(0, 13),
+      }
+    );
+  }
+
+  #[test]
+  fn test_attribute_without_arguments() {
+    let (remaining_input, attribute) = Attribute::parse("@id".into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(
+      attribute,
+      Attribute { name: String::from("id"), arguments: vec![], span: Span::new(0, 3) }
+    );
+
+    let (remaining_input, attribute) = Attribute::parse("@updatedAt".into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(
+      attribute,
+      Attribute { name: String::from("updatedAt"), arguments: vec![], span: Span::new(0, 10) }
+    );
+  }
+
+  #[test]
+  fn test_attribute_with_arguments() {
+    let (remaining_input, attribute) = Attribute::parse(r#"@map("user_id")"#.into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(
+      attribute,
+      Attribute {
+        name: String::from("map"),
+        arguments: vec![positional(string("user_id", 5))],
+        span: Span::new(0, 15),
+      }
+    );
+
+    let (remaining_input, attribute) =
+      Attribute::parse(r#"@default(dbgenerated("gen_random_uuid()"))"#.into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(
+      attribute,
+      Attribute {
+        name: String::from("default"),
+        arguments: vec![positional(function(
+          "dbgenerated",
+          vec![positional(string("gen_random_uuid()", 21))],
+          Span::new(9, 41),
+        ))],
+        span: Span::new(0, 42),
+      }
+    );
+
+    let (remaining_input, attribute) =
+      Attribute::parse(r#"@unique(map: "email_key")"#.into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(
+      attribute,
+      Attribute {
+        name: String::from("unique"),
+        arguments: vec![Argument {
+          name: Some(String::from("map")),
+          value: string("email_key", 13),
+          span: Span::new(8, 24),
+        }],
+        span: Span::new(0, 25),
+      }
+    );
+  }
+
+  #[test]
+  fn test_native_type_attribute() {
+    let (remaining_input, attribute) = Attribute::parse("@db.Uuid".into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(
+      attribute,
+      Attribute { name: String::from("db.Uuid"), arguments: vec![], span: Span::new(0, 8) }
+    );
+
+    let schema = "@db.Decimal(10, 2)";
+    let (remaining_input, attribute) = Attribute::parse(schema.into()).unwrap();
+    assert!(remaining_input.is_empty());
+    let numeric = |value: &str, start| Expression::NumericValue {
+      value: String::from(value),
+      span: Span::new(start, start + value.len()),
+    };
+    assert_eq!(
+      attribute,
+      Attribute {
+        name: String::from("db.Decimal"),
+        arguments: vec![positional(numeric("10", 12)), positional(numeric("2", 16))],
+        span: Span::new(0, schema.len()),
+      }
+    );
+
+    // only one prefix is allowed
+    let (remaining_input, attribute) = Attribute::parse("@db.Text.Foo".into()).unwrap();
+    assert_eq!(attribute.name, "db.Text");
+    assert_eq!(*remaining_input.fragment(), ".Foo");
+  }
+
+  #[test]
+  fn test_block_attribute() {
+    let (remaining_input, attribute) = Attribute::parse_block(r#"@@map("users")"#.into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(
+      attribute,
+      Attribute {
+        name: String::from("map"),
+        arguments: vec![positional(string("users", 6))],
+        span: Span::new(0, 14),
+      }
+    );
+
+    let (remaining_input, attribute) = Attribute::parse_block("@@ignore".into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(
+      attribute,
+      Attribute { name: String::from("ignore"), arguments: vec![], span: Span::new(0, 8) }
+    );
+
+    // block attributes are not field attributes, and vice versa
+    assert!(Attribute::parse("@@ignore".into()).is_err());
+    assert!(Attribute::parse_block("@ignore".into()).is_err());
+  }
+
+  #[test]
+  fn test_block_attribute_with_field_list() {
+    let schema = r#"@@index([title, createdAt(sort: Desc)], name: "title_idx", type: Hash)"#;
+    let (remaining_input, attribute) = Attribute::parse_block(schema.into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(
+      attribute,
+      Attribute {
+        name: String::from("index"),
+        arguments: vec![
+          positional(Expression::Array {
+            value: vec![
+              constant("title", 9),
+              function(
+                "createdAt",
+                vec![Argument {
+                  name: Some(String::from("sort")),
+                  value: constant("Desc", 32),
+                  span: Span::new(26, 36),
+                }],
+                Span::new(16, 37),
+              ),
+            ],
+            span: Span::new(8, 38),
+          }),
+          Argument {
+            name: Some(String::from("name")),
+            value: string("title_idx", 46),
+            span: Span::new(40, 57),
+          },
+          Argument {
+            name: Some(String::from("type")),
+            value: constant("Hash", 65),
+            span: Span::new(59, 69),
+          },
+        ],
+        span: Span::new(0, 70),
+      }
+    );
+  }
+}
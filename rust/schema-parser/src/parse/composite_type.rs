@@ -0,0 +1,99 @@
+use crate::ast::composite_type::CompositeType;
+use crate::ast::model::Field;
+use crate::parseutil::{
+  parse_block_end, parse_documentation, parse_identifier, parse_item_end, parse_keyword,
+  parse_trivia, spanned, ws,
+};
+use nom::character::complete::char;
+use nom::combinator::cut;
+use nom::multi::many0;
+use nom::sequence::{delimited, pair, preceded, terminated};
+
+use super::parser::{IResult, Input, Parser};
+
+impl Parser for CompositeType {
+  /// Parse a composite type block, optionally preceded by documentation comments, e.g.,
+  /// /// Address of a user.
+  /// type Address {
+  ///   street String
+  ///   city   String
+  /// }
+  fn parse(input: Input) -> IResult<Self> {
+    pair(
+      parse_documentation,
+      terminated(
+        spanned(preceded(
+          terminated(parse_keyword("type"), parse_trivia),
+          cut(pair(
+            parse_identifier,
+            delimited(
+              ws(char('{')),
+              many0(ws(terminated(Field::parse, cut(parse_item_end)))),
+              parse_block_end("a field or `}`"),
+            ),
+          )),
+        )),
+        parse_trivia,
+      ),
+    )(input)
+    .map(|(rest, (documentation, ((name, fields), span)))| {
+      (rest, Self { name: name.to_owned(), documentation, fields, span })
+    })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::ast::model::FieldArity;
+  use crate::ast::span::Span;
+
+  #[test]
+  fn test_composite_type() {
+    let schema = r#"
+      /// Address of a user.
+      type Address {
+        street String
+        zip    String?
+      }
+    "#;
+
+    let (remaining_input, composite_type) = CompositeType::parse(schema.into()).unwrap();
+    assert!(remaining_input.is_empty());
+
+    assert_eq!(
+      composite_type,
+      CompositeType {
+        name: String::from("Address"),
+        documentation: Some(String::from("Address of a user.")),
+        fields: vec![
+          Field {
+            name: String::from("street"),
+            documentation: None,
+            field_type: String::from("String"),
+            arity: FieldArity::Required,
+            attributes: vec![],
+            span: Span::of(schema, "street String"),
+          },
+          Field {
+            name: String::from("zip"),
+            documentation: None,
+            field_type: String::from("String"),
+            arity: FieldArity::Optional,
+            attributes: vec![],
+            span: Span::of(schema, "zip    String?"),
+          },
+        ],
+        span: Span::new(schema.find("type").unwrap(), schema.rfind('}').unwrap() + 1),
+      }
+    );
+
+    // block attributes aren't supported
+    let schema = "type Address {\n  @@map(\"addresses\")\n}";
+    let error = CompositeType::parse(schema.into()).unwrap_err();
+    assert!(matches!(error, nom::Err::Failure(_)));
+
+    // "type" is a keyword only if followed by whitespace
+    assert!(CompositeType::parse("types Address {}".into()).is_err());
+  }
+}
@@ -0,0 +1,224 @@
+use crate::ast::attribute::Attribute;
+use crate::ast::r#enum::{Enum, EnumValue};
+use crate::parseutil::{
+  parse_block_end, parse_documentation, parse_identifier, parse_item_end, parse_keyword,
+  parse_trivia, spanned, ws,
+};
+use nom::branch::alt;
+use nom::character::complete::{char, space0};
+use nom::combinator::{cut, map};
+use nom::multi::many0;
+use nom::sequence::{delimited, pair, preceded, terminated};
+
+use super::parser::{IResult, Input, Parser};
+
+impl Parser for EnumValue {
+  /// Parse an enum value, e.g., `ADMIN @map("admin")`, optionally preceded by
+  /// documentation comments.
+  /// It expects no whitespace prefix.
+  fn parse(input: Input) -> IResult<Self> {
+    pair(
+      parse_documentation,
+      spanned(pair(parse_identifier, many0(preceded(space0, Attribute::parse)))),
+    )(input)
+    .map(|(rest, (documentation, ((name, attributes), span)))| {
+      (rest, Self { name: name.to_owned(), documentation, attributes, span })
+    })
+  }
+}
+
+/// Item of an enum body: either a value or a block attribute.
+enum EnumItem {
+  Value(EnumValue),
+  Attribute(Attribute),
+}
+
+impl EnumItem {
+  fn parse(input: Input) -> IResult<Self> {
+    alt((
+      map(EnumValue::parse, Self::Value),
+      map(preceded(parse_documentation, Attribute::parse_block), Self::Attribute),
+    ))(input)
+  }
+}
+
+impl Parser for Enum {
+  /// Parse an enum block, optionally preceded by documentation comments, e.g.,
+  /// /// Role of a user.
+  /// enum Role {
+  ///   USER
+  ///   ADMIN @map("admin")
+  ///
+  ///   @@map("roles")
+  /// }
+  fn parse(input: Input) -> IResult<Self> {
+    pair(
+      parse_documentation,
+      terminated(
+        spanned(preceded(
+          terminated(parse_keyword("enum"), parse_trivia),
+          cut(pair(
+            parse_identifier,
+            delimited(
+              ws(char('{')),
+              many0(ws(terminated(EnumItem::parse, cut(parse_item_end)))),
+              parse_block_end("an enum value, a block attribute or `}`"),
+            ),
+          )),
+        )),
+        parse_trivia,
+      ),
+    )(input)
+    .map(|(rest, (documentation, ((name, enum_items), span)))| {
+      let mut values = vec![];
+      let mut attributes = vec![];
+      for enum_item in enum_items {
+        match enum_item {
+          EnumItem::Value(value) => values.push(value),
+          EnumItem::Attribute(attribute) => attributes.push(attribute),
+        }
+      }
+      (rest, Self { name: name.to_owned(), documentation, values, attributes, span })
+    })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::ast::attribute::{Argument, Expression};
+  use crate::ast::span::Span;
+
+  /// Build the @map("...") attribute found in the given source.
+  fn map_attribute(source: &str, name: &str) -> Attribute {
+    let attribute = format!(r#"@map("{name}")"#);
+    let value = Expression::StringValue {
+      value: String::from(name),
+      span: Span::of(source, &format!(r#""{name}""#)),
+    };
+    Attribute {
+      name: String::from("map"),
+      arguments: vec![Argument { name: None, span: value.span(), value }],
+      span: Span::of(source, &attribute),
+    }
+  }
+
+  #[test]
+  fn test_enum_value() {
+    let (remaining_input, value) = EnumValue::parse("USER".into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(
+      value,
+      EnumValue {
+        name: String::from("USER"),
+        documentation: None,
+        attributes: vec![],
+        span: Span::new(0, 4),
+      }
+    );
+
+    let schema = r#"ADMIN @map("admin")"#;
+    let (remaining_input, value) = EnumValue::parse(schema.into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(
+      value,
+      EnumValue {
+        name: String::from("ADMIN"),
+        documentation: None,
+        attributes: vec![map_attribute(schema, "admin")],
+        span: Span::new(0, schema.len()),
+      }
+    );
+  }
+
+  #[test]
+  fn test_enum() {
+    /* formatted */
+    let schema = r#"
+      enum Role {
+        USER
+        ADMIN @map("admin")
+
+        @@map("roles")
+      }
+    "#;
+
+    let (remaining_input, r#enum) = Enum::parse(schema.into()).unwrap();
+    assert!(remaining_input.is_empty());
+
+    let block_start = schema.find("enum").unwrap();
+    let block_end = schema.find('}').unwrap() + 1;
+    let mut roles_attribute = map_attribute(schema, "roles");
+    roles_attribute.span = Span::of(schema, r#"@@map("roles")"#);
+
+    assert_eq!(
+      r#enum,
+      Enum {
+        name: String::from("Role"),
+        documentation: None,
+        values: vec![
+          EnumValue {
+            name: String::from("USER"),
+            documentation: None,
+            attributes: vec![],
+            span: Span::of(schema, "USER"),
+          },
+          EnumValue {
+            name: String::from("ADMIN"),
+            documentation: None,
+            attributes: vec![map_attribute(schema, "admin")],
+            span: Span::of(schema, r#"ADMIN @map("admin")"#),
+          },
+        ],
+        attributes: vec![roles_attribute],
+        span: Span::new(block_start, block_end),
+      }
+    );
+
+    /* compact spacing */
+    let schema = "enum Color{RED\nGREEN}";
+
+    let (remaining_input, r#enum) = Enum::parse(schema.into()).unwrap();
+    assert!(remaining_input.is_empty());
+
+    assert_eq!(
+      r#enum.values.iter().map(|value| value.name.as_str()).collect::<Vec<_>>(),
+      vec!["RED", "GREEN"]
+    );
+    assert_eq!(r#enum.span, Span::new(0, schema.len()));
+
+    /* values on the same line */
+    let schema = "enum Color {\n  RED GREEN\n}";
+    let error = match Enum::parse(schema.into()) {
+      Err(nom::Err::Failure(error)) => crate::validate::diagnostics::DatamodelError::from(error),
+      _ => panic!("expected a parser error"),
+    };
+    assert_eq!(error.message, "Unexpected `GREEN`, expected end of line.");
+    assert_eq!(error.span, Span::of(schema, "GREEN"));
+  }
+
+  #[test]
+  fn test_enum_with_comments() {
+    let schema = r#"
+      /// Role of a user.
+      enum Role {
+        // This comment is ignored.
+        USER // This comment is ignored, too.
+        /// Can do anything.
+        ADMIN
+      }
+    "#;
+
+    let (remaining_input, r#enum) = Enum::parse(schema.into()).unwrap();
+    assert!(remaining_input.is_empty());
+
+    assert_eq!(r#enum.documentation, Some(String::from("Role of a user.")));
+    assert_eq!(r#enum.values[0].documentation, None);
+    assert_eq!(r#enum.values[1].documentation, Some(String::from("Can do anything.")));
+
+    // documentation and comments are not part of the spans
+    assert_eq!(r#enum.span.start, schema.find("enum").unwrap());
+    assert_eq!(r#enum.values[0].span, Span::of(schema, "USER"));
+    assert_eq!(r#enum.values[1].span, Span::of(schema, "ADMIN"));
+  }
+}
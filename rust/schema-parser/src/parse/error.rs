@@ -0,0 +1,207 @@
+use std::num::ParseIntError;
+
+use nom::error::{ContextError, ErrorKind, FromExternalError, ParseError};
+
+use crate::ast::span::Span;
+use crate::validate::diagnostics::{DatamodelError, ErrorCode};
+
+use super::parser::Input;
+
+/// Error of every parser, recording where the parser failed, what it expected, and what it found
+/// instead. Alternatives that fail at the same position have their expectations merged, and the
+/// alternative that got further into the schema wins, so that the error is as precise as possible.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ParserError {
+  /// The schema doesn't match the grammar, e.g., a block isn't closed.
+  Unexpected {
+    /// Descriptions of what the parser expected, e.g., "`{`" or "a field type".
+    expected: Vec<String>,
+
+    /// Description of the token found instead, e.g., "`modle`" or "end of file".
+    found: String,
+
+    /// Span of the token found instead.
+    span: Span,
+  },
+
+  /// The schema matches the grammar, but it's invalid, e.g., a required property is missing.
+  Invalid(DatamodelError),
+}
+
+impl ParserError {
+  pub(crate) fn unexpected(input: Input, expected: Vec<String>) -> Self {
+    let (found, span) = describe_token(input);
+    Self::Unexpected { expected, found, span }
+  }
+
+  fn offset(&self) -> usize {
+    match self {
+      Self::Unexpected { span, .. } => span.start,
+      Self::Invalid(error) => error.span.start,
+    }
+  }
+}
+
+/// Describe the token at the start of the given input, returning its description and span.
+fn describe_token(input: Input) -> (String, Span) {
+  let start = input.location_offset();
+  let fragment = *input.fragment();
+  let word_len = fragment
+    .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+    .unwrap_or(fragment.len());
+
+  match fragment.chars().next() {
+    None => (String::from("end of file"), Span::new(start, start)),
+    Some('\r' | '\n') => (String::from("end of line"), Span::new(start, start)),
+    Some(c) if c.is_whitespace() => (String::from("whitespace"), Span::new(start, start + 1)),
+    Some(c) if word_len == 0 => (format!("`{c}`"), Span::new(start, start + c.len_utf8())),
+    Some(_) => (format!("`{}`", &fragment[..word_len]), Span::new(start, start + word_len)),
+  }
+}
+
+/// Describe what a nom parser expects, if its error kind is meaningful to users.
+fn describe_error_kind(kind: ErrorKind) -> Option<&'static str> {
+  match kind {
+    ErrorKind::Alpha => Some("a letter"),
+    ErrorKind::Digit => Some("a number"),
+    ErrorKind::CrLf => Some("end of line"),
+    ErrorKind::Eof => Some("end of file"),
+    ErrorKind::Space | ErrorKind::MultiSpace => Some("whitespace"),
+    _ => None,
+  }
+}
+
+impl<'a> ParseError<Input<'a>> for ParserError {
+  fn from_error_kind(input: Input<'a>, kind: ErrorKind) -> Self {
+    Self::unexpected(input, describe_error_kind(kind).map(String::from).into_iter().collect())
+  }
+
+  fn append(_input: Input<'a>, _kind: ErrorKind, other: Self) -> Self {
+    other
+  }
+
+  fn from_char(input: Input<'a>, c: char) -> Self {
+    Self::unexpected(input, vec![format!("`{c}`")])
+  }
+
+  fn or(self, other: Self) -> Self {
+    match (self, other) {
+      (
+        Self::Unexpected { mut expected, found, span },
+        Self::Unexpected { expected: other_expected, span: other_span, .. },
+      ) if span == other_span => {
+        for description in other_expected {
+          if !expected.contains(&description) {
+            expected.push(description);
+          }
+        }
+        Self::Unexpected { expected, found, span }
+      }
+      (error, other) if error.offset() > other.offset() => error,
+      (_, other) => other,
+    }
+  }
+}
+
+impl<'a> ContextError<Input<'a>> for ParserError {
+  /// Replace what the parser expected with a description of the whole construct, e.g.,
+  /// "a value" instead of "`\"`, `[` or an identifier", unless the parser failed midway through
+  /// the construct: in that case, the more specific expectation is kept.
+  fn add_context(input: Input<'a>, context: &'static str, other: Self) -> Self {
+    match other {
+      Self::Unexpected { found, span, .. } if span.start == input.location_offset() => {
+        Self::Unexpected { expected: vec![String::from(context)], found, span }
+      }
+      other => other,
+    }
+  }
+}
+
+impl<'a> FromExternalError<Input<'a>, DatamodelError> for ParserError {
+  fn from_external_error(_input: Input<'a>, _kind: ErrorKind, error: DatamodelError) -> Self {
+    Self::Invalid(error)
+  }
+}
+
+impl<'a> FromExternalError<Input<'a>, ParseIntError> for ParserError {
+  fn from_external_error(input: Input<'a>, _kind: ErrorKind, _error: ParseIntError) -> Self {
+    Self::unexpected(input, vec![String::from("a hexadecimal unicode code point")])
+  }
+}
+
+impl From<ParserError> for DatamodelError {
+  fn from(error: ParserError) -> Self {
+    match error {
+      ParserError::Unexpected { expected, found, span } => {
+        let message = match expected.split_last() {
+          None => format!("Unexpected {found}."),
+          Some((last, [])) => format!("Unexpected {found}, expected {last}."),
+          Some((last, rest)) => {
+            format!("Unexpected {found}, expected {} or {last}.", rest.join(", "))
+          }
+        };
+        DatamodelError::new(ErrorCode::SyntaxError, message, span)
+      }
+      ParserError::Invalid(error) => error,
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use nom::branch::alt;
+  use nom::bytes::complete::tag;
+  use nom::character::complete::char;
+  use nom::error::context;
+  use nom::sequence::preceded;
+
+  fn parse_error<O>(result: nom::IResult<Input, O, ParserError>) -> DatamodelError {
+    match result {
+      Err(nom::Err::Error(error) | nom::Err::Failure(error)) => error.into(),
+      _ => panic!("expected a parser error"),
+    }
+  }
+
+  #[test]
+  fn test_unexpected_token() {
+    let error = parse_error(char::<_, ParserError>('{')(Input::new("modle User")));
+    assert_eq!(error.code, ErrorCode::SyntaxError);
+    assert_eq!(error.message, "Unexpected `modle`, expected `{`.");
+    assert_eq!(error.span, Span::new(0, 5));
+
+    let error = parse_error(char::<_, ParserError>('{')(Input::new("")));
+    assert_eq!(error.message, "Unexpected end of file, expected `{`.");
+    assert_eq!(error.span, Span::new(0, 0));
+  }
+
+  #[test]
+  fn test_alternatives() {
+    // expectations at the same position are merged
+    let error = parse_error(alt((char::<_, ParserError>('['), char('"'), char('(')))(Input::new(
+      "}",
+    )));
+    assert_eq!(error.message, "Unexpected `}`, expected `[`, `\"` or `(`.");
+
+    // the alternative that got further wins
+    let error = parse_error(alt((
+      preceded(tag::<_, _, ParserError>("env("), char('"')),
+      preceded(tag("en"), char('x')),
+    ))(Input::new("env(42")));
+    assert_eq!(error.message, "Unexpected `42`, expected `\"`.");
+    assert_eq!(error.span, Span::new(4, 6));
+  }
+
+  #[test]
+  fn test_context() {
+    let mut parse_value = context("a value", alt((char::<_, ParserError>('['), char('"'))));
+
+    let error = parse_error(parse_value(Input::new("\n")));
+    assert_eq!(error.message, "Unexpected end of line, expected a value.");
+
+    // the context doesn't hide where a construct failed midway
+    let mut parse_pair = context("a pair", preceded(char::<_, ParserError>('('), char(')')));
+    let error = parse_error(parse_pair(Input::new("(x")));
+    assert_eq!(error.message, "Unexpected `x`, expected `)`.");
+  }
+}
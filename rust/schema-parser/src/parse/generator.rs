@@ -0,0 +1,245 @@
+use std::collections::BTreeMap;
+
+use crate::ast::generator::{Generator, GeneratorValue};
+use crate::ast::span::{Span, Spanned};
+use crate::parseutil::{
+  parse_block_end, parse_documentation, parse_env, parse_identifier, parse_keyword,
+  parse_string_quoted, parse_trivia, spanned, ws, ws_inline,
+};
+use crate::validate::diagnostics::{DatamodelError, ErrorCode};
+use nom::branch::alt;
+use nom::character::complete::char;
+use nom::combinator::{cut, map, opt};
+use nom::error::context;
+use nom::multi::{many0, separated_list0};
+use nom::sequence::{delimited, pair, preceded, separated_pair, terminated};
+
+use super::error::ParserError;
+use super::parser::{IResult, Input, Parser};
+
+impl GeneratorValue {
+  fn parse_array(input: Input) -> IResult<Self> {
+    map(
+      spanned(preceded(
+        ws(char('[')),
+        cut(terminated(
+          separated_list0(
+            ws(char(',')),
+            ws(map(spanned(parse_string_quoted('"')), |(value, span)| Spanned::new(value, span))),
+          ),
+          terminated(opt(ws(char(','))), char(']')),
+        )),
+      )),
+      |(value, span)| Self::Array { value, span },
+    )(input)
+  }
+
+  /// Interpret the value of the given property as a list of strings, e.g., for previewFeatures.
+  /// A single string is treated as a list with one element.
+  fn into_string_list(self, property: &str) -> Result<Vec<Spanned<String>>, DatamodelError> {
+    match self {
+      Self::String { value, span } => Ok(vec![Spanned::new(value, span)]),
+      Self::Array { value, .. } => Ok(value),
+      Self::Env { span, .. } => Err(DatamodelError::new(
+        ErrorCode::InvalidPropertyValue,
+        format!("The generator property \"{property}\" must be a string or a list of strings."),
+        span,
+      )),
+    }
+  }
+}
+
+impl Parser for GeneratorValue {
+  /// Parse a generator property value, e.g.:
+  /// - "prisma-client-js" (String)
+  /// - ["fullTextSearch", "metrics"] (Array)
+  /// - env("GENERATOR_OUTPUT") (Env)
+  ///
+  /// It expects no whitespace prefix.
+  fn parse(input: Input) -> IResult<Self> {
+    alt((
+      map(spanned(parse_string_quoted('"')), |(value, span)| Self::String { value, span }),
+      Self::parse_array,
+      map(spanned(parse_env), |(value, span)| Self::Env { value, span }),
+    ))(input)
+  }
+}
+
+impl Generator {
+  /// Parses a generator property, e.g., provider = "prisma-client-js".
+  /// Documentation comments before a property are discarded.
+  fn parse_property<'a>(input: Input<'a>) -> IResult<'a, (&'a str, GeneratorValue)> {
+    preceded(
+      parse_documentation,
+      separated_pair(
+        parse_identifier,
+        cut(ws_inline(char('='))),
+        cut(context(r#"a string, a list of strings or env("...")"#, GeneratorValue::parse)),
+      ),
+    )(input)
+  }
+
+  /// Build a generator from its list of properties.
+  /// Fails if a known property has a value of the wrong kind,
+  /// e.g., previewFeatures = env("PREVIEW_FEATURES").
+  fn from_properties(
+    documentation: Option<String>,
+    name: &str,
+    properties: Vec<(&str, GeneratorValue)>,
+    span: Span,
+  ) -> Result<Self, DatamodelError> {
+    let mut generator = Self {
+      name: name.to_owned(),
+      documentation,
+      provider: None,
+      output: None,
+      preview_features: vec![],
+      binary_targets: vec![],
+      config: BTreeMap::new(),
+      span,
+    };
+
+    for (key, value) in properties {
+      match key {
+        "provider" => generator.provider = Some(value),
+        "output" => generator.output = Some(value),
+        "previewFeatures" => generator.preview_features = value.into_string_list(key)?,
+        "binaryTargets" => generator.binary_targets = value.into_string_list(key)?,
+        _ => {
+          generator.config.insert(key.to_owned(), value);
+        }
+      }
+    }
+
+    Ok(generator)
+  }
+}
+
+impl Parser for Generator {
+  /// Parse a generator block, optionally preceded by documentation comments, e.g.,
+  /// generator client {
+  ///   provider        = "prisma-client-js"
+  ///   previewFeatures = ["fullTextSearch"]
+  /// }
+  fn parse(input: Input) -> IResult<Self> {
+    pair(
+      parse_documentation,
+      terminated(
+        spanned(preceded(
+          terminated(parse_keyword("generator"), parse_trivia),
+          cut(pair(
+            parse_identifier,
+            delimited(
+              ws(char('{')),
+              many0(ws(Self::parse_property)),
+              parse_block_end("a property or `}`"),
+            ),
+          )),
+        )),
+        parse_trivia,
+      ),
+    )(input)
+    .and_then(|(rest, (documentation, ((name, properties), span)))| {
+      Self::from_properties(documentation, name, properties, span)
+        .map(|generator| (rest, generator))
+        .map_err(|error| nom::Err::Failure(ParserError::Invalid(error)))
+    })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_generator_value() {
+    let (remaining_input, value) = GeneratorValue::parse(r#""prisma-client-js""#.into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(
+      value,
+      GeneratorValue::String { value: String::from("prisma-client-js"), span: Span::new(0, 18) }
+    );
+
+    let (remaining_input, value) =
+      GeneratorValue::parse(r#"["native", "rhel-openssl-1.0.x"]"#.into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(
+      value,
+      GeneratorValue::Array {
+        value: vec![
+          Spanned::new(String::from("native"), Span::new(1, 9)),
+          Spanned::new(String::from("rhel-openssl-1.0.x"), Span::new(11, 31)),
+        ],
+        span: Span::new(0, 32),
+      }
+    );
+
+    let (remaining_input, value) =
+      GeneratorValue::parse(r#"env("GENERATOR_OUTPUT")"#.into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(
+      value,
+      GeneratorValue::Env { value: String::from("GENERATOR_OUTPUT"), span: Span::new(0, 23) }
+    );
+  }
+
+  #[test]
+  fn test_generator() {
+    /* formatted */
+    let schema = r#"
+      generator client {
+        provider        = "prisma-client-js" // the default client
+        /// Documentation for a property is discarded.
+        output          = env("GENERATOR_OUTPUT")
+        previewFeatures = ["fullTextSearch", "metrics"]
+        binaryTargets   = ["native"]
+        engineType      = "binary"
+      }
+    "#;
+
+    let (remaining_input, generator) = Generator::parse(schema.into()).unwrap();
+    assert!(remaining_input.is_empty());
+
+    let block_start = schema.find("generator").unwrap();
+    let block_end = schema.find('}').unwrap() + 1;
+
+    assert_eq!(
+      generator,
+      Generator {
+        name: String::from("client"),
+        documentation: None,
+        provider: Some(GeneratorValue::String {
+          value: String::from("prisma-client-js"),
+          span: Span::of(schema, r#""prisma-client-js""#),
+        }),
+        output: Some(GeneratorValue::Env {
+          value: String::from("GENERATOR_OUTPUT"),
+          span: Span::of(schema, r#"env("GENERATOR_OUTPUT")"#),
+        }),
+        preview_features: vec![
+          Spanned::new(String::from("fullTextSearch"), Span::of(schema, r#""fullTextSearch""#)),
+          Spanned::new(String::from("metrics"), Span::of(schema, r#""metrics""#)),
+        ],
+        binary_targets: vec![Spanned::new(String::from("native"), Span::of(schema, r#""native""#))],
+        config: BTreeMap::from([(
+          String::from("engineType"),
+          GeneratorValue::String {
+            value: String::from("binary"),
+            span: Span::of(schema, r#""binary""#),
+          }
+        )]),
+        span: Span::new(block_start, block_end),
+      }
+    );
+
+    /* known property with the wrong kind of value */
+    let schema = r#"
+      generator client {
+        provider        = "prisma-client-js"
+        previewFeatures = env("PREVIEW_FEATURES")
+      }
+    "#;
+
+    assert!(Generator::parse(schema.into()).is_err());
+  }
+}
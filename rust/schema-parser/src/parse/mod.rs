@@ -1,4 +1,10 @@
+pub mod attribute;
+pub mod composite_type;
 pub mod datasource;
 pub mod datasource_db;
+pub mod r#enum;
+pub mod error;
+pub mod generator;
+pub mod model;
 pub mod parser;
 pub mod schema;
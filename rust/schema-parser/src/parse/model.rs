@@ -0,0 +1,331 @@
+use crate::ast::attribute::Attribute;
+use crate::ast::model::{Field, FieldArity, Model};
+use crate::parseutil::{
+  parse_block_end, parse_documentation, parse_identifier, parse_item_end, parse_keyword,
+  parse_trivia, spanned, ws,
+};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{char, space0, space1};
+use nom::combinator::{cut, map, success, value};
+use nom::error::context;
+use nom::multi::many0;
+use nom::sequence::{delimited, pair, preceded, terminated, tuple};
+
+use super::parser::{IResult, Input, Parser};
+
+impl Parser for FieldArity {
+  /// Parse the optional arity modifier that follows a field type, e.g., "?" or "[]".
+  /// It expects no whitespace prefix, and it never fails.
+  fn parse(input: Input) -> IResult<Self> {
+    alt((
+      value(Self::List, tag("[]")),
+      value(Self::Optional, char('?')),
+      success(Self::Required),
+    ))(input)
+  }
+}
+
+impl Parser for Field {
+  /// Parse a model field, e.g., "email String? @unique", optionally preceded by
+  /// documentation comments.
+  /// It expects no whitespace prefix.
+  fn parse(input: Input) -> IResult<Self> {
+    pair(
+      parse_documentation,
+      spanned(tuple((
+        parse_identifier,
+        cut(context("a field type", preceded(space1, parse_identifier))),
+        FieldArity::parse,
+        many0(preceded(space0, Attribute::parse)),
+      ))),
+    )(input)
+    .map(|(rest, (documentation, ((name, field_type, arity, attributes), span)))| {
+      (
+        rest,
+        Self {
+          name: name.to_owned(),
+          documentation,
+          field_type: field_type.to_owned(),
+          arity,
+          attributes,
+          span,
+        },
+      )
+    })
+  }
+}
+
+/// Item of a model body: either a field or a block attribute.
+enum ModelItem {
+  Field(Field),
+  Attribute(Attribute),
+}
+
+impl ModelItem {
+  fn parse(input: Input) -> IResult<Self> {
+    alt((
+      map(Field::parse, Self::Field),
+      map(preceded(parse_documentation, Attribute::parse_block), Self::Attribute),
+    ))(input)
+  }
+}
+
+impl Parser for Model {
+  /// Parse a model block, optionally preceded by documentation comments, e.g.,
+  /// /// A user of the blog.
+  /// model User {
+  ///   id    Int
+  ///   email String?
+  ///   posts Post[]
+  ///
+  ///   @@map("users")
+  /// }
+  fn parse(input: Input) -> IResult<Self> {
+    pair(
+      parse_documentation,
+      terminated(
+        spanned(preceded(
+          terminated(parse_keyword("model"), parse_trivia),
+          cut(pair(
+            parse_identifier,
+            delimited(
+              ws(char('{')),
+              many0(ws(terminated(ModelItem::parse, cut(parse_item_end)))),
+              parse_block_end("a field, a block attribute or `}`"),
+            ),
+          )),
+        )),
+        parse_trivia,
+      ),
+    )(input)
+    .map(|(rest, (documentation, ((name, model_items), span)))| {
+      let mut fields = vec![];
+      let mut attributes = vec![];
+      for model_item in model_items {
+        match model_item {
+          ModelItem::Field(field) => fields.push(field),
+          ModelItem::Attribute(attribute) => attributes.push(attribute),
+        }
+      }
+      (rest, Self { name: name.to_owned(), documentation, fields, attributes, span })
+    })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::ast::attribute::{Argument, Expression, FunctionCall};
+  use crate::ast::span::Span;
+
+  #[test]
+  fn test_field_arity() {
+    let (remaining_input, arity) = FieldArity::parse("[]".into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(arity, FieldArity::List);
+
+    let (remaining_input, arity) = FieldArity::parse("?".into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(arity, FieldArity::Optional);
+
+    let (remaining_input, arity) = FieldArity::parse("".into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(arity, FieldArity::Required);
+  }
+
+  #[test]
+  fn test_field() {
+    let (remaining_input, field) = Field::parse("email   String?".into()).unwrap();
+    assert!(remaining_input.is_empty());
+
+    assert_eq!(
+      field,
+      Field {
+        name: String::from("email"),
+        documentation: None,
+        field_type: String::from("String"),
+        arity: FieldArity::Optional,
+        attributes: vec![],
+        span: Span::new(0, 15),
+      }
+    );
+
+    let (remaining_input, field) =
+      Field::parse(r#"id String @id @default(uuid()) @map("user_id")"#.into()).unwrap();
+    assert!(remaining_input.is_empty());
+
+    assert_eq!(field.name, "id");
+    assert_eq!(
+      field.attributes.iter().map(|attribute| attribute.name.as_str()).collect::<Vec<_>>(),
+      vec!["id", "default", "map"]
+    );
+    assert_eq!(field.span, Span::new(0, 46));
+
+    // trailing whitespace is not part of the span
+    let (remaining_input, field) = Field::parse("id Int @id  \n".into()).unwrap();
+    assert_eq!(*remaining_input.fragment(), "  \n");
+    assert_eq!(field.span, Span::new(0, 10));
+  }
+
+  #[test]
+  fn test_model() {
+    /* formatted */
+    let schema = r#"
+      model User {
+        id    Int     @id @default(autoincrement())
+        email String? @unique
+        posts Post[]
+      }
+    "#;
+
+    let (remaining_input, model) = Model::parse(schema.into()).unwrap();
+    assert!(remaining_input.is_empty());
+
+    let block_start = schema.find("model").unwrap();
+    let block_end = schema.find('}').unwrap() + 1;
+
+    assert_eq!(
+      model,
+      Model {
+        name: String::from("User"),
+        documentation: None,
+        fields: vec![
+          Field {
+            name: String::from("id"),
+            documentation: None,
+            field_type: String::from("Int"),
+            arity: FieldArity::Required,
+            attributes: vec![
+              Attribute {
+                name: String::from("id"),
+                arguments: vec![],
+                span: Span::of(schema, "@id"),
+              },
+              Attribute {
+                name: String::from("default"),
+                arguments: vec![Argument {
+                  name: None,
+                  value: Expression::Function(FunctionCall {
+                    name: String::from("autoincrement"),
+                    arguments: vec![],
+                    span: Span::of(schema, "autoincrement()"),
+                  }),
+                  span: Span::of(schema, "autoincrement()"),
+                }],
+                span: Span::of(schema, "@default(autoincrement())"),
+              },
+            ],
+            span: Span::of(schema, "id    Int     @id @default(autoincrement())"),
+          },
+          Field {
+            name: String::from("email"),
+            documentation: None,
+            field_type: String::from("String"),
+            arity: FieldArity::Optional,
+            attributes: vec![Attribute {
+              name: String::from("unique"),
+              arguments: vec![],
+              span: Span::of(schema, "@unique"),
+            }],
+            span: Span::of(schema, "email String? @unique"),
+          },
+          Field {
+            name: String::from("posts"),
+            documentation: None,
+            field_type: String::from("Post"),
+            arity: FieldArity::List,
+            attributes: vec![],
+            span: Span::of(schema, "posts Post[]"),
+          },
+        ],
+        attributes: vec![],
+        span: Span::new(block_start, block_end),
+      }
+    );
+
+    /* empty model */
+    let schema = r#"model Empty {}"#;
+
+    let (remaining_input, model) = Model::parse(schema.into()).unwrap();
+    assert!(remaining_input.is_empty());
+    assert_eq!(
+      model,
+      Model {
+        name: String::from("Empty"),
+        documentation: None,
+        fields: vec![],
+        attributes: vec![],
+        span: Span::new(0, schema.len()),
+      }
+    );
+
+    /* with block attributes */
+    let schema = r#"
+      model Follows {
+        @@map("follows")
+        followerId Int
+        followingId Int
+
+        @@id([followerId, followingId])
+        @@index([followingId], map: "following_idx")
+      }
+    "#;
+
+    let (remaining_input, model) = Model::parse(schema.into()).unwrap();
+    assert!(remaining_input.is_empty());
+
+    assert_eq!(
+      model.fields.iter().map(|field| field.name.as_str()).collect::<Vec<_>>(),
+      vec!["followerId", "followingId"]
+    );
+    assert_eq!(
+      model.attributes.iter().map(|attribute| attribute.name.as_str()).collect::<Vec<_>>(),
+      vec!["map", "id", "index"]
+    );
+    assert_eq!(model.attributes[1].span, Span::of(schema, "@@id([followerId, followingId])"));
+
+    /* fields on the same line */
+    let schema = "model User {\n  id Int name String\n}";
+    let error = match Model::parse(schema.into()) {
+      Err(nom::Err::Failure(error)) => crate::validate::diagnostics::DatamodelError::from(error),
+      _ => panic!("expected a parser error"),
+    };
+    assert_eq!(error.message, "Unexpected `name`, expected end of line.");
+    assert_eq!(error.span, Span::of(schema, "name"));
+  }
+
+  #[test]
+  fn test_model_with_comments() {
+    let schema = r#"
+      // This comment is ignored.
+      /// A user of the blog.
+      /// Users can write posts.
+      model User {
+        /// Primary key.
+        id    Int     @id // This comment is ignored, too.
+
+        // Another ignored comment.
+        email String?
+        /// Documentation for a block attribute is discarded.
+        @@map("users")
+        /// Dangling documentation is discarded, too.
+      }
+    "#;
+
+    let (remaining_input, model) = Model::parse(schema.into()).unwrap();
+    assert!(remaining_input.is_empty());
+
+    assert_eq!(
+      model.documentation,
+      Some(String::from("A user of the blog.\nUsers can write posts."))
+    );
+    assert_eq!(model.fields[0].documentation, Some(String::from("Primary key.")));
+    assert_eq!(model.fields[1].documentation, None);
+    assert_eq!(model.attributes.len(), 1);
+
+    // documentation and comments are not part of the spans
+    assert_eq!(model.span.start, schema.find("model").unwrap());
+    assert_eq!(model.fields[0].span, Span::of(schema, "id    Int     @id"));
+  }
+}
@@ -1,15 +1,16 @@
-use nom::IResult;
+use nom_locate::LocatedSpan;
+
+use super::error::ParserError;
+
+/// Input of every parser: a slice of the schema source that knows its byte offset
+/// from the start of the schema, so that parsed nodes can be given a `Span`.
+pub type Input<'a> = LocatedSpan<&'a str>;
+
+/// Result of every parser, with `Input` as input type and `ParserError` as error type.
+pub type IResult<'a, O> = nom::IResult<Input<'a>, O, ParserError>;
 
 pub trait Parser {
-  fn parse(input: &str) -> IResult<&str, Self>
+  fn parse(input: Input) -> IResult<Self>
   where
     Self: Sized;
 }
-
-macro_rules! parse_alt_enum {
-  ($s:expr) => {
-    value($s.into(), tag($s))
-  };
-}
-
-pub(crate) use parse_alt_enum;
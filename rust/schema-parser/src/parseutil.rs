@@ -1,24 +1,29 @@
 use nom::branch::alt;
-use nom::bytes::streaming::{is_not, take_while_m_n};
-use nom::character::complete::{multispace0, space0};
-use nom::character::streaming::{char, multispace1};
-use nom::combinator::{map, map_opt, map_res, value, verify};
-use nom::error::{FromExternalError, ParseError};
-use nom::multi::fold_many0;
-use nom::sequence::{delimited, preceded};
+use nom::bytes::complete::{is_not, tag, take_while_m_n};
+use nom::character::complete::{
+  alpha1, alphanumeric1, char, line_ending, multispace1, not_line_ending, satisfy, space0, space1,
+};
+use nom::combinator::{eof, map, map_opt, map_res, not, opt, peek, recognize, value, verify};
+use nom::error::{context, ContextError, ErrorKind, FromExternalError, ParseError};
+use nom::multi::{fold_many0, many0, many0_count};
+use nom::sequence::{delimited, pair, preceded, terminated, tuple};
 use nom::IResult;
 
-/// Apply a generic parser f potentially surrounded by whitespace, potentially consuming carriage returns and line feeds.
-/// F is the type of the input parser, I is the input type (e.g., &str),
-/// O is the output type (e.g., DatasourceDb), E is the parser error type.
-pub fn ws<F, I, O, E>(f: F) -> impl FnMut(I) -> IResult<I, O, E>
+use crate::ast::span::Span;
+use crate::parse::parser::Input;
+use crate::validate::diagnostics::{DatamodelError, ErrorCode};
+
+/// Apply a generic parser f potentially surrounded by whitespace and line comments,
+/// potentially consuming carriage returns and line feeds.
+/// Documentation comments are never consumed, see `parse_documentation`.
+/// F is the type of the input parser, O is the output type (e.g., DatasourceDb),
+/// E is the parser error type.
+pub fn ws<'a, F, O, E>(f: F) -> impl FnMut(Input<'a>) -> IResult<Input<'a>, O, E>
 where
-  F: FnMut(I) -> IResult<I, O, E>,
-  I: nom::InputTakeAtPosition,
-  <I as nom::InputTakeAtPosition>::Item: nom::AsChar + Clone,
-  E: nom::error::ParseError<I>,
+  F: FnMut(Input<'a>) -> IResult<Input<'a>, O, E>,
+  E: ParseError<Input<'a>>,
 {
-  delimited(multispace0, f, multispace0)
+  delimited(parse_trivia, f, parse_trivia)
 }
 
 /// Apply a generic parser f potentially surrounded by whitespace, without consuming carriage returns and line feeds.
@@ -34,12 +39,120 @@ where
   delimited(space0, f, space0)
 }
 
+/// Parse a line comment, e.g., "// comment", up to (but excluding) the line ending.
+/// Documentation comments, e.g., "/// documentation", are not line comments.
+pub fn parse_line_comment<'a, E: ParseError<Input<'a>>>(
+  input: Input<'a>,
+) -> IResult<Input<'a>, Input<'a>, E> {
+  recognize(tuple((tag("//"), not(char('/')), not_line_ending)))(input)
+}
+
+/// Parse any amount of whitespace and line comments, which have no meaning in a schema.
+pub fn parse_trivia<'a, E: ParseError<Input<'a>>>(input: Input<'a>) -> IResult<Input<'a>, (), E> {
+  value((), many0_count(alt((multispace1, parse_line_comment))))(input)
+}
+
+/// Parse a single documentation comment line, e.g., "/// The email of the user",
+/// returning its text without the "///" prefix and the first space.
+fn parse_doc_comment_line<'a, E: ParseError<Input<'a>>>(
+  input: Input<'a>,
+) -> IResult<Input<'a>, &'a str, E> {
+  map(preceded(tag("///"), not_line_ending), |line: Input<'a>| {
+    let line = *line.fragment();
+    line.strip_prefix(' ').unwrap_or(line).trim_end()
+  })(input)
+}
+
+/// Parse any number of consecutive documentation comments, together with the
+/// surrounding trivia, joining their lines with "\n".
+/// Returns None if there are no documentation comments.
+pub fn parse_documentation<'a, E: ParseError<Input<'a>>>(
+  input: Input<'a>,
+) -> IResult<Input<'a>, Option<String>, E> {
+  map(
+    terminated(many0(preceded(parse_trivia, parse_doc_comment_line)), parse_trivia),
+    |lines: Vec<&str>| if lines.is_empty() { None } else { Some(lines.join("\n")) },
+  )(input)
+}
+
+/// Parse the closing brace of a block, together with any documentation comment that
+/// doesn't belong to a block member, e.g., one written right before the closing brace.
+/// Trailing whitespace isn't consumed, so that the span of the block ends at the closing brace.
+/// `expected` describes what the block accepts, e.g., "a field, a block attribute or `}`",
+/// and it's reported when neither a block member nor the closing brace is found.
+pub fn parse_block_end<'a, E>(
+  expected: &'static str,
+) -> impl FnMut(Input<'a>) -> IResult<Input<'a>, char, E>
+where
+  E: ParseError<Input<'a>> + ContextError<Input<'a>>,
+{
+  preceded(parse_documentation, context(expected, char('}')))
+}
+
+/// Parse the end of a block item, e.g., a field or an enum value: trailing whitespace and an
+/// optional line comment, followed by a line ending, so that every item is on its own line.
+/// The closing brace of the block may follow the last item on the same line.
+pub fn parse_item_end<'a, E>(input: Input<'a>) -> IResult<Input<'a>, (), E>
+where
+  E: ParseError<Input<'a>> + ContextError<Input<'a>>,
+{
+  value(
+    (),
+    tuple((
+      space0,
+      opt(parse_line_comment),
+      context("end of line", value((), alt((line_ending, peek(tag("}")), eof)))),
+    )),
+  )(input)
+}
+
+/// Parse a keyword, e.g., "model", making sure it's not the prefix of a longer identifier,
+/// e.g., "models".
+pub fn parse_keyword<'a, E>(
+  keyword: &'static str,
+) -> impl FnMut(Input<'a>) -> IResult<Input<'a>, Input<'a>, E>
+where
+  E: ParseError<Input<'a>>,
+{
+  terminated(tag(keyword), not(satisfy(|c| c.is_alphanumeric() || c == '_')))
+}
+
+/// Apply a generic parser f, returning its output together with the span of the input
+/// it consumed.
+pub fn spanned<'a, F, O, E>(
+  mut f: F,
+) -> impl FnMut(Input<'a>) -> IResult<Input<'a>, (O, Span), E>
+where
+  F: FnMut(Input<'a>) -> IResult<Input<'a>, O, E>,
+  E: ParseError<Input<'a>>,
+{
+  move |input: Input<'a>| {
+    let start = input.location_offset();
+    let (rest, output) = f(input)?;
+    Ok((rest, (output, Span::new(start, rest.location_offset()))))
+  }
+}
+
+/// Parse an identifier, e.g., a model name or a field name.
+/// Identifiers start with a letter, followed by any number of letters, digits, or underscores.
+pub fn parse_identifier<'a, E>(input: Input<'a>) -> IResult<Input<'a>, &'a str, E>
+where
+  E: ParseError<Input<'a>> + ContextError<Input<'a>>,
+{
+  context(
+    "an identifier",
+    map(recognize(pair(alpha1, many0(alt((alphanumeric1, tag("_")))))), |identifier: Input<'a>| {
+      *identifier.fragment()
+    }),
+  )(input)
+}
+
 /// Parse a unicode sequence, of the form u{XXXX}, where XXXX is 1 to 6
 /// hexadecimal numerals. We will combine this later with parse_escaped_char
 /// to parse sequences like \u{00AC}.
-fn parse_unicode<'a, E>(input: &'a str) -> IResult<&'a str, char, E>
+fn parse_unicode<'a, E>(input: Input<'a>) -> IResult<Input<'a>, char, E>
 where
-  E: ParseError<&'a str> + FromExternalError<&'a str, std::num::ParseIntError>,
+  E: ParseError<Input<'a>> + FromExternalError<Input<'a>, std::num::ParseIntError>,
 {
   // `take_while_m_n` parses between `m` and `n` bytes (inclusive) that match
   // a predicate. `parse_hex` here parses between 1 and 6 hexadecimal numerals.
@@ -58,7 +171,8 @@ where
   // `map_res` takes the result of a parser and applies a function that returns
   // a Result. In this case we take the hex bytes from parse_hex and attempt to
   // convert them to a u32.
-  let parse_u32 = map_res(parse_delimited_hex, move |hex| u32::from_str_radix(hex, 16));
+  let parse_u32 =
+    map_res(parse_delimited_hex, move |hex: Input<'a>| u32::from_str_radix(hex.fragment(), 16));
 
   // map_opt is like map_res, but it takes an Option instead of a Result. If
   // the function returns None, map_opt returns an error. In this case, because
@@ -68,9 +182,9 @@ where
 }
 
 /// Parse an escaped character: \n, \t, \r, \u{00AC}, etc.
-fn parse_escaped_char<'a, E>(input: &'a str) -> IResult<&'a str, char, E>
+fn parse_escaped_char<'a, E>(input: Input<'a>) -> IResult<Input<'a>, char, E>
 where
-  E: ParseError<&'a str> + FromExternalError<&'a str, std::num::ParseIntError>,
+  E: ParseError<Input<'a>> + FromExternalError<Input<'a>, std::num::ParseIntError>,
 {
   preceded(
     char('\\'),
@@ -94,25 +208,27 @@ where
   )(input)
 }
 
-/// Parse a backslash, followed by any amount of whitespace. This is used later
+/// Parse a backslash, followed by any amount of whitespace on the same line. This is used later
 /// to discard any escaped whitespace.
-fn parse_escaped_whitespace<'a, E: ParseError<&'a str>>(
-  input: &'a str,
-) -> IResult<&'a str, &'a str, E> {
-  preceded(char('\\'), multispace1)(input)
+fn parse_escaped_whitespace<'a, E: ParseError<Input<'a>>>(
+  input: Input<'a>,
+) -> IResult<Input<'a>, Input<'a>, E> {
+  preceded(char('\\'), space1)(input)
 }
 
-/// Parse a non-empty block of text that doesn't include \ or "
-fn parse_literal<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, &'a str, E> {
+/// Parse a non-empty block of text that doesn't include \, " or a line ending
+fn parse_literal<'a, E: ParseError<Input<'a>>>(
+  input: Input<'a>,
+) -> IResult<Input<'a>, Input<'a>, E> {
   // `is_not` parses a string of 0 or more characters that aren't one of the
-  // given characters.
-  let not_quote_slash = is_not("\"\\");
+  // given characters. Strings never span multiple lines.
+  let not_quote_slash = is_not("\"\\\r\n");
 
   // `verify` runs a parser, then runs a verification function on the output of
   // the parser. The verification function accepts out output only if it
   // returns true. In this case, we want to ensure that the output of is_not
   // is non-empty.
-  verify(not_quote_slash, |s: &str| !s.is_empty())(input)
+  verify(not_quote_slash, |s: &Input<'a>| !s.fragment().is_empty())(input)
 }
 
 /// A string fragment contains a fragment of a string being parsed: either
@@ -120,16 +236,16 @@ fn parse_literal<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str,
 /// parsed escaped character, or a block of escaped whitespace.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum StringFragment<'a> {
-  Literal(&'a str),
+  Literal(Input<'a>),
   EscapedChar(char),
   EscapedWS,
 }
 
 /// Combine parse_literal, parse_escaped_whitespace, and parse_escaped_char
 /// into a StringFragment.
-fn parse_fragment<'a, E>(input: &'a str) -> IResult<&'a str, StringFragment<'a>, E>
+fn parse_fragment<'a, E>(input: Input<'a>) -> IResult<Input<'a>, StringFragment<'a>, E>
 where
-  E: ParseError<&'a str> + FromExternalError<&'a str, std::num::ParseIntError>,
+  E: ParseError<Input<'a>> + FromExternalError<Input<'a>, std::num::ParseIntError>,
 {
   alt((
     // The `map` combinator runs a parser, then applies a function to the output
@@ -142,13 +258,19 @@ where
 
 /// Parse a string. Use a loop of parse_fragment and push all of the fragments
 /// into an output string.
-pub fn parse_string_quoted<'a, E>(quote: char) -> impl FnMut(&'a str) -> IResult<&'a str, String, E>
+/// A string that isn't closed before the end of the line is reported as unterminated, at its
+/// opening quote, and the error isn't recoverable: no alternative can parse the rest of the line.
+pub fn parse_string_quoted<'a, E>(
+  quote: char,
+) -> impl FnMut(Input<'a>) -> IResult<Input<'a>, String, E>
 where
-  E: ParseError<&'a str> + FromExternalError<&'a str, std::num::ParseIntError>,
+  E: ParseError<Input<'a>>
+    + FromExternalError<Input<'a>, std::num::ParseIntError>
+    + FromExternalError<Input<'a>, DatamodelError>,
 {
   // fold_many0 is the equivalent of iterator::fold. It runs a parser in a loop,
   // and for each output value, calls a folding function on each output value.
-  let build_string = fold_many0(
+  let mut build_string = fold_many0(
     // Our parser function– parses a single string fragment
     parse_fragment,
     // Our init value, an empty string
@@ -157,7 +279,7 @@ where
     // string.
     |mut string, fragment| {
       match fragment {
-        StringFragment::Literal(s) => string.push_str(s),
+        StringFragment::Literal(s) => string.push_str(s.fragment()),
         StringFragment::EscapedChar(c) => string.push(c),
         StringFragment::EscapedWS => {}
       }
@@ -166,22 +288,125 @@ where
   );
 
   // Finally, parse the string. Note that, if `build_string` could accept a raw
-  // " character, the closing delimiter " would never match. When using
-  // `delimited` with a looping parser (like fold_many0), be sure that the
+  // " character, the closing delimiter " would never match. When following
+  // a looping parser (like fold_many0) with a delimiter, be sure that the
   // loop won't accidentally match your closing delimiter!
-  delimited(char(quote), build_string, char(quote))
+  move |input: Input<'a>| {
+    let (rest, _) = char(quote)(input)?;
+    let (rest, string) = build_string(rest)?;
+    match char::<_, E>(quote)(rest) {
+      Ok((rest, _)) => Ok((rest, string)),
+      // the string stops at an invalid escape sequence, e.g., "\q"
+      Err(error) if rest.fragment().starts_with('\\') => Err(error),
+      Err(_) => {
+        let start = input.location_offset();
+        let error = DatamodelError::new(
+          ErrorCode::SyntaxError,
+          format!("Unterminated string, expected a closing `{quote}` before the end of the line."),
+          Span::new(start, start + quote.len_utf8()),
+        );
+        Err(nom::Err::Failure(E::from_external_error(input, ErrorKind::Char, error)))
+      }
+    }
+  }
+}
+
+/// Parse a reference to an environment variable, e.g., env("DATABASE_URL"),
+/// returning the name of the variable.
+pub fn parse_env<'a, E>(input: Input<'a>) -> IResult<Input<'a>, String, E>
+where
+  E: ParseError<Input<'a>>
+    + FromExternalError<Input<'a>, std::num::ParseIntError>
+    + FromExternalError<Input<'a>, DatamodelError>,
+{
+  delimited(tag("env("), parse_string_quoted('"'), char(')'))(input)
 }
 
 #[cfg(test)]
 mod test {
   use super::*;
+  use crate::parse::error::ParserError;
 
   #[test]
   fn test_parse_string_quoted_latin() {
     let input = r#""EXAMPLE_STRING""#;
-    let (remaining_input, provider) = parse_string_quoted::<()>('"')(input).unwrap();
+    let (remaining_input, provider) = parse_string_quoted::<()>('"')(input.into()).unwrap();
 
     assert!(remaining_input.is_empty());
     assert_eq!(provider, String::from("EXAMPLE_STRING"));
   }
+
+  #[test]
+  fn test_parse_string_quoted_unterminated() {
+    let input = "  \"EXAMPLE_STRING\n  next line\"";
+    let result = preceded(space0, parse_string_quoted::<ParserError>('"'))(input.into());
+    let error = match result {
+      Err(nom::Err::Failure(error)) => DatamodelError::from(error),
+      _ => panic!("expected an unrecoverable parser error"),
+    };
+
+    assert_eq!(error.code, ErrorCode::SyntaxError);
+    assert_eq!(
+      error.message,
+      "Unterminated string, expected a closing `\"` before the end of the line."
+    );
+    assert_eq!(error.span, Span::new(2, 3));
+
+    // strings that end with the schema are unterminated too
+    let result = parse_string_quoted::<ParserError>('"')("\"EXAMPLE".into());
+    assert!(matches!(result, Err(nom::Err::Failure(_))));
+  }
+
+  #[test]
+  fn test_parse_env() {
+    let input = r#"env("DATABASE_URL")"#;
+    let (remaining_input, env_var) = parse_env::<()>(input.into()).unwrap();
+
+    assert!(remaining_input.is_empty());
+    assert_eq!(env_var, String::from("DATABASE_URL"));
+  }
+
+  #[test]
+  fn test_ws_skips_line_comments() {
+    let input = "  // a comment\n  // another comment\n  model // trailing comment\n";
+    let (remaining_input, keyword) = ws::<_, _, ()>(tag("model"))(input.into()).unwrap();
+
+    assert!(remaining_input.is_empty());
+    assert_eq!(*keyword.fragment(), "model");
+  }
+
+  #[test]
+  fn test_ws_keeps_doc_comments() {
+    let input = "// a comment\n/// documentation\n";
+    let (remaining_input, _) = parse_trivia::<()>(input.into()).unwrap();
+
+    assert_eq!(*remaining_input.fragment(), "/// documentation\n");
+  }
+
+  #[test]
+  fn test_parse_item_end() {
+    for input in ["\nnext", "  // comment\nnext", " }", ""] {
+      assert!(parse_item_end::<ParserError>(input.into()).is_ok(), "{input:?}");
+    }
+
+    let error = match parse_item_end::<ParserError>("  name String".into()) {
+      Err(nom::Err::Error(error)) => DatamodelError::from(error),
+      _ => panic!("expected a parser error"),
+    };
+    assert_eq!(error.message, "Unexpected `name`, expected end of line.");
+    assert_eq!(error.span, Span::new(2, 6));
+  }
+
+  #[test]
+  fn test_parse_documentation() {
+    let input = "\n  /// The user's email.\n  // not documentation\n  ///Must be unique.\n  email";
+    let (remaining_input, documentation) = parse_documentation::<()>(input.into()).unwrap();
+
+    assert_eq!(*remaining_input.fragment(), "email");
+    assert_eq!(documentation, Some(String::from("The user's email.\nMust be unique.")));
+
+    let (remaining_input, documentation) = parse_documentation::<()>("  email".into()).unwrap();
+    assert_eq!(*remaining_input.fragment(), "email");
+    assert_eq!(documentation, None);
+  }
 }
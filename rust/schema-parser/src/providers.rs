@@ -0,0 +1,583 @@
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use bitflags::bitflags;
+
+use crate::ast::datasource_db::{Provider, RelationMode};
+use crate::ast::model::{ReferentialAction, ScalarType};
+
+bitflags! {
+  /// Schema features that only some providers support.
+  pub struct Capabilities: u8 {
+    const ENUMS = 1 << 0;
+    /// Lists of scalars or enums, e.g., `tags String[]`.
+    const SCALAR_LISTS = 1 << 1;
+    /// Native type attributes, e.g., `@db.VarChar(255)`.
+    const NATIVE_TYPES = 1 << 2;
+    /// Full-text indexes, i.e., `@@fulltext`.
+    const FULL_TEXT_INDEX = 1 << 3;
+    /// Composite types, i.e., `type` blocks.
+    const COMPOSITE_TYPES = 1 << 4;
+    /// Multiple database schemas, i.e., `schemas` in the datasource and `@@schema`.
+    const MULTI_SCHEMA = 1 << 5;
+  }
+}
+
+impl Capabilities {
+  /// Describe the features of the given capabilities, e.g., for error messages.
+  pub fn describe(&self) -> Vec<&'static str> {
+    [
+      (Self::ENUMS, "enums"),
+      (Self::SCALAR_LISTS, "scalar lists"),
+      (Self::NATIVE_TYPES, "native types"),
+      (Self::FULL_TEXT_INDEX, "full-text indexes"),
+      (Self::COMPOSITE_TYPES, "composite types"),
+      (Self::MULTI_SCHEMA, "multiple schemas"),
+    ]
+    .into_iter()
+    .filter(|(capability, _)| self.contains(*capability))
+    .map(|(_, description)| description)
+    .collect()
+  }
+}
+
+/// A native type, e.g., VarChar in `@db.VarChar(255)`.
+#[derive(Debug)]
+pub struct NativeType {
+  pub name: &'static str,
+
+  /// Accepted numbers of arguments, e.g., [0, 2] for both `Decimal` and `Decimal(10, 2)`.
+  /// Arguments are always integers.
+  pub arguments: &'static [usize],
+
+  /// Scalar types of the fields the native type can be used on.
+  pub scalar_types: &'static [ScalarType],
+}
+
+const fn native_type(
+  name: &'static str,
+  arguments: &'static [usize],
+  scalar_types: &'static [ScalarType],
+) -> NativeType {
+  NativeType { name, arguments, scalar_types }
+}
+
+/// Everything that's specific to a provider. The parser, the validator and the connection string
+/// parser all read from `PROVIDERS`, so supporting a new provider means adding a variant to
+/// `Provider` and an entry here.
+#[derive(Debug)]
+pub struct ProviderInfo {
+  pub provider: Provider,
+
+  /// Name of the provider in the schema, e.g., "postgres".
+  pub name: &'static str,
+
+  /// Whether schemas can use the provider. Unsupported providers are still parsed, so that the
+  /// validator reports them with the list of supported ones.
+  pub supported: bool,
+
+  /// Schemes accepted in URLs, the first being the preferred one.
+  pub url_schemes: &'static [&'static str],
+
+  /// Parameters accepted in URLs, e.g., "schema" in postgres://localhost/db?schema=public.
+  pub url_params: &'static [&'static str],
+
+  /// Whether URL parameters are compared case-insensitively.
+  pub case_insensitive_url_params: bool,
+
+  /// Schema features supported by the provider.
+  pub capabilities: Capabilities,
+
+  /// Native types, used via `@db.<Type>(args)` if the provider supports `NATIVE_TYPES`.
+  pub native_types: &'static [NativeType],
+
+  /// Referential actions supported by foreign keys, i.e., with relationMode = "foreignKeys".
+  pub referential_actions: &'static [ReferentialAction],
+
+  /// Relation mode used when the datasource doesn't define relationMode.
+  pub relation_mode: RelationMode,
+}
+
+pub const PROVIDERS: &[ProviderInfo] = &[
+  ProviderInfo {
+    provider: Provider::Postgres,
+    name: "postgres",
+    supported: true,
+    url_schemes: &["postgresql", "postgres"],
+    url_params: POSTGRES_PARAMS,
+    case_insensitive_url_params: false,
+    capabilities: Capabilities::ENUMS
+      .union(Capabilities::SCALAR_LISTS)
+      .union(Capabilities::NATIVE_TYPES)
+      .union(Capabilities::MULTI_SCHEMA),
+    native_types: POSTGRES_NATIVE_TYPES,
+    referential_actions: ALL_ACTIONS,
+    relation_mode: RelationMode::ForeignKeys,
+  },
+  ProviderInfo {
+    provider: Provider::CockroachDb,
+    name: "cockroachdb",
+    supported: false,
+    url_schemes: &["postgresql", "postgres"],
+    url_params: POSTGRES_PARAMS,
+    case_insensitive_url_params: false,
+    capabilities: Capabilities::ENUMS
+      .union(Capabilities::SCALAR_LISTS)
+      .union(Capabilities::NATIVE_TYPES)
+      .union(Capabilities::MULTI_SCHEMA),
+    native_types: COCKROACHDB_NATIVE_TYPES,
+    referential_actions: ALL_ACTIONS,
+    relation_mode: RelationMode::ForeignKeys,
+  },
+  ProviderInfo {
+    provider: Provider::MySQL,
+    name: "mysql",
+    supported: false,
+    url_schemes: &["mysql"],
+    url_params: MYSQL_PARAMS,
+    case_insensitive_url_params: false,
+    capabilities: Capabilities::ENUMS
+      .union(Capabilities::NATIVE_TYPES)
+      .union(Capabilities::FULL_TEXT_INDEX),
+    native_types: MYSQL_NATIVE_TYPES,
+    referential_actions: MYSQL_ACTIONS,
+    relation_mode: RelationMode::ForeignKeys,
+  },
+  ProviderInfo {
+    provider: Provider::MariaDb,
+    name: "mariadb",
+    supported: false,
+    url_schemes: &["mysql"],
+    url_params: MYSQL_PARAMS,
+    case_insensitive_url_params: false,
+    capabilities: Capabilities::ENUMS
+      .union(Capabilities::NATIVE_TYPES)
+      .union(Capabilities::FULL_TEXT_INDEX),
+    native_types: MYSQL_NATIVE_TYPES,
+    referential_actions: MYSQL_ACTIONS,
+    relation_mode: RelationMode::ForeignKeys,
+  },
+  ProviderInfo {
+    provider: Provider::SQLServer,
+    name: "sqlserver",
+    supported: false,
+    url_schemes: &["sqlserver"],
+    url_params: SQLSERVER_PARAMS,
+    case_insensitive_url_params: true,
+    capabilities: Capabilities::NATIVE_TYPES.union(Capabilities::MULTI_SCHEMA),
+    native_types: SQLSERVER_NATIVE_TYPES,
+    referential_actions: SQLSERVER_ACTIONS,
+    relation_mode: RelationMode::ForeignKeys,
+  },
+  ProviderInfo {
+    provider: Provider::SQLite,
+    name: "sqlite",
+    supported: true,
+    url_schemes: &["file"],
+    url_params: SQLITE_PARAMS,
+    case_insensitive_url_params: false,
+    capabilities: Capabilities::empty(),
+    native_types: &[],
+    referential_actions: ALL_ACTIONS,
+    relation_mode: RelationMode::ForeignKeys,
+  },
+  ProviderInfo {
+    provider: Provider::MongoDb,
+    name: "mongodb",
+    supported: false,
+    url_schemes: &["mongodb", "mongodb+srv"],
+    url_params: MONGODB_PARAMS,
+    case_insensitive_url_params: true,
+    capabilities: Capabilities::ENUMS
+      .union(Capabilities::NATIVE_TYPES)
+      .union(Capabilities::FULL_TEXT_INDEX)
+      .union(Capabilities::COMPOSITE_TYPES),
+    native_types: MONGODB_NATIVE_TYPES,
+    referential_actions: EMULATED_REFERENTIAL_ACTIONS,
+    relation_mode: RelationMode::Prisma,
+  },
+];
+
+/// Referential actions supported when relations are emulated, i.e., with relationMode = "prisma".
+pub const EMULATED_REFERENTIAL_ACTIONS: &[ReferentialAction] = &[
+  ReferentialAction::Cascade,
+  ReferentialAction::Restrict,
+  ReferentialAction::NoAction,
+  ReferentialAction::SetNull,
+];
+
+const ALL_ACTIONS: &[ReferentialAction] = ReferentialAction::ALL;
+
+const MYSQL_ACTIONS: &[ReferentialAction] = &[
+  ReferentialAction::Cascade,
+  ReferentialAction::Restrict,
+  ReferentialAction::NoAction,
+  ReferentialAction::SetNull,
+];
+
+const SQLSERVER_ACTIONS: &[ReferentialAction] = &[
+  ReferentialAction::Cascade,
+  ReferentialAction::NoAction,
+  ReferentialAction::SetNull,
+  ReferentialAction::SetDefault,
+];
+
+const NO_ARGUMENTS: &[usize] = &[0];
+const OPTIONAL_ARGUMENT: &[usize] = &[0, 1];
+const ONE_ARGUMENT: &[usize] = &[1];
+const PRECISION_AND_SCALE: &[usize] = &[0, 2];
+
+const POSTGRES_NATIVE_TYPES: &[NativeType] = &[
+  native_type("SmallInt", NO_ARGUMENTS, &[ScalarType::Int]),
+  native_type("Integer", NO_ARGUMENTS, &[ScalarType::Int]),
+  native_type("BigInt", NO_ARGUMENTS, &[ScalarType::BigInt]),
+  native_type("Oid", NO_ARGUMENTS, &[ScalarType::Int]),
+  native_type("Decimal", PRECISION_AND_SCALE, &[ScalarType::Decimal]),
+  native_type("Money", NO_ARGUMENTS, &[ScalarType::Decimal]),
+  native_type("Real", NO_ARGUMENTS, &[ScalarType::Float]),
+  native_type("DoublePrecision", NO_ARGUMENTS, &[ScalarType::Float]),
+  native_type("Text", NO_ARGUMENTS, &[ScalarType::String]),
+  native_type("VarChar", OPTIONAL_ARGUMENT, &[ScalarType::String]),
+  native_type("Char", OPTIONAL_ARGUMENT, &[ScalarType::String]),
+  native_type("Citext", NO_ARGUMENTS, &[ScalarType::String]),
+  native_type("Uuid", NO_ARGUMENTS, &[ScalarType::String]),
+  native_type("Inet", NO_ARGUMENTS, &[ScalarType::String]),
+  native_type("Xml", NO_ARGUMENTS, &[ScalarType::String]),
+  native_type("Bit", OPTIONAL_ARGUMENT, &[ScalarType::String]),
+  native_type("VarBit", OPTIONAL_ARGUMENT, &[ScalarType::String]),
+  native_type("Boolean", NO_ARGUMENTS, &[ScalarType::Boolean]),
+  native_type("Timestamp", OPTIONAL_ARGUMENT, &[ScalarType::DateTime]),
+  native_type("Timestamptz", OPTIONAL_ARGUMENT, &[ScalarType::DateTime]),
+  native_type("Date", NO_ARGUMENTS, &[ScalarType::DateTime]),
+  native_type("Time", OPTIONAL_ARGUMENT, &[ScalarType::DateTime]),
+  native_type("Timetz", OPTIONAL_ARGUMENT, &[ScalarType::DateTime]),
+  native_type("Json", NO_ARGUMENTS, &[ScalarType::Json]),
+  native_type("JsonB", NO_ARGUMENTS, &[ScalarType::Json]),
+  native_type("ByteA", NO_ARGUMENTS, &[ScalarType::Bytes]),
+];
+
+const COCKROACHDB_NATIVE_TYPES: &[NativeType] = &[
+  native_type("Int2", NO_ARGUMENTS, &[ScalarType::Int]),
+  native_type("Int4", NO_ARGUMENTS, &[ScalarType::Int]),
+  native_type("Int8", NO_ARGUMENTS, &[ScalarType::BigInt]),
+  native_type("Oid", NO_ARGUMENTS, &[ScalarType::Int]),
+  native_type("Decimal", PRECISION_AND_SCALE, &[ScalarType::Decimal]),
+  native_type("Float4", NO_ARGUMENTS, &[ScalarType::Float]),
+  native_type("Float8", NO_ARGUMENTS, &[ScalarType::Float]),
+  native_type("String", OPTIONAL_ARGUMENT, &[ScalarType::String]),
+  native_type("Char", OPTIONAL_ARGUMENT, &[ScalarType::String]),
+  native_type("CatalogSingleChar", NO_ARGUMENTS, &[ScalarType::String]),
+  native_type("Uuid", NO_ARGUMENTS, &[ScalarType::String]),
+  native_type("Inet", NO_ARGUMENTS, &[ScalarType::String]),
+  native_type("Bit", OPTIONAL_ARGUMENT, &[ScalarType::String]),
+  native_type("VarBit", OPTIONAL_ARGUMENT, &[ScalarType::String]),
+  native_type("Bool", NO_ARGUMENTS, &[ScalarType::Boolean]),
+  native_type("Timestamp", OPTIONAL_ARGUMENT, &[ScalarType::DateTime]),
+  native_type("Timestamptz", OPTIONAL_ARGUMENT, &[ScalarType::DateTime]),
+  native_type("Date", NO_ARGUMENTS, &[ScalarType::DateTime]),
+  native_type("Time", OPTIONAL_ARGUMENT, &[ScalarType::DateTime]),
+  native_type("Timetz", OPTIONAL_ARGUMENT, &[ScalarType::DateTime]),
+  native_type("JsonB", NO_ARGUMENTS, &[ScalarType::Json]),
+  native_type("Bytes", NO_ARGUMENTS, &[ScalarType::Bytes]),
+];
+
+/// Native types of both MySQL and MariaDB.
+const MYSQL_NATIVE_TYPES: &[NativeType] = &[
+  native_type("TinyInt", NO_ARGUMENTS, &[ScalarType::Boolean, ScalarType::Int]),
+  native_type("UnsignedTinyInt", NO_ARGUMENTS, &[ScalarType::Boolean, ScalarType::Int]),
+  native_type("SmallInt", NO_ARGUMENTS, &[ScalarType::Int]),
+  native_type("UnsignedSmallInt", NO_ARGUMENTS, &[ScalarType::Int]),
+  native_type("MediumInt", NO_ARGUMENTS, &[ScalarType::Int]),
+  native_type("UnsignedMediumInt", NO_ARGUMENTS, &[ScalarType::Int]),
+  native_type("Int", NO_ARGUMENTS, &[ScalarType::Int]),
+  native_type("UnsignedInt", NO_ARGUMENTS, &[ScalarType::Int]),
+  native_type("BigInt", NO_ARGUMENTS, &[ScalarType::BigInt]),
+  native_type("UnsignedBigInt", NO_ARGUMENTS, &[ScalarType::BigInt]),
+  native_type("Year", NO_ARGUMENTS, &[ScalarType::Int]),
+  native_type("Decimal", PRECISION_AND_SCALE, &[ScalarType::Decimal]),
+  native_type("Float", NO_ARGUMENTS, &[ScalarType::Float]),
+  native_type("Double", NO_ARGUMENTS, &[ScalarType::Float]),
+  native_type("Bit", ONE_ARGUMENT, &[ScalarType::Boolean, ScalarType::Bytes]),
+  native_type("Char", ONE_ARGUMENT, &[ScalarType::String]),
+  native_type("VarChar", ONE_ARGUMENT, &[ScalarType::String]),
+  native_type("TinyText", NO_ARGUMENTS, &[ScalarType::String]),
+  native_type("Text", NO_ARGUMENTS, &[ScalarType::String]),
+  native_type("MediumText", NO_ARGUMENTS, &[ScalarType::String]),
+  native_type("LongText", NO_ARGUMENTS, &[ScalarType::String]),
+  native_type("Binary", ONE_ARGUMENT, &[ScalarType::Bytes]),
+  native_type("VarBinary", ONE_ARGUMENT, &[ScalarType::Bytes]),
+  native_type("TinyBlob", NO_ARGUMENTS, &[ScalarType::Bytes]),
+  native_type("Blob", NO_ARGUMENTS, &[ScalarType::Bytes]),
+  native_type("MediumBlob", NO_ARGUMENTS, &[ScalarType::Bytes]),
+  native_type("LongBlob", NO_ARGUMENTS, &[ScalarType::Bytes]),
+  native_type("Date", NO_ARGUMENTS, &[ScalarType::DateTime]),
+  native_type("Time", OPTIONAL_ARGUMENT, &[ScalarType::DateTime]),
+  native_type("DateTime", OPTIONAL_ARGUMENT, &[ScalarType::DateTime]),
+  native_type("Timestamp", OPTIONAL_ARGUMENT, &[ScalarType::DateTime]),
+  native_type("Json", NO_ARGUMENTS, &[ScalarType::Json]),
+];
+
+const SQLSERVER_NATIVE_TYPES: &[NativeType] = &[
+  native_type("TinyInt", NO_ARGUMENTS, &[ScalarType::Int]),
+  native_type("SmallInt", NO_ARGUMENTS, &[ScalarType::Int]),
+  native_type("Int", NO_ARGUMENTS, &[ScalarType::Int]),
+  native_type("BigInt", NO_ARGUMENTS, &[ScalarType::BigInt]),
+  native_type("Decimal", PRECISION_AND_SCALE, &[ScalarType::Decimal]),
+  native_type("Money", NO_ARGUMENTS, &[ScalarType::Float]),
+  native_type("SmallMoney", NO_ARGUMENTS, &[ScalarType::Float]),
+  native_type("Float", OPTIONAL_ARGUMENT, &[ScalarType::Float]),
+  native_type("Real", NO_ARGUMENTS, &[ScalarType::Float]),
+  native_type("Bit", NO_ARGUMENTS, &[ScalarType::Boolean, ScalarType::Int]),
+  native_type("Char", OPTIONAL_ARGUMENT, &[ScalarType::String]),
+  native_type("NChar", OPTIONAL_ARGUMENT, &[ScalarType::String]),
+  native_type("VarChar", OPTIONAL_ARGUMENT, &[ScalarType::String]),
+  native_type("NVarChar", OPTIONAL_ARGUMENT, &[ScalarType::String]),
+  native_type("Text", NO_ARGUMENTS, &[ScalarType::String]),
+  native_type("NText", NO_ARGUMENTS, &[ScalarType::String]),
+  native_type("Xml", NO_ARGUMENTS, &[ScalarType::String]),
+  native_type("UniqueIdentifier", NO_ARGUMENTS, &[ScalarType::String]),
+  native_type("Date", NO_ARGUMENTS, &[ScalarType::DateTime]),
+  native_type("Time", NO_ARGUMENTS, &[ScalarType::DateTime]),
+  native_type("DateTime", NO_ARGUMENTS, &[ScalarType::DateTime]),
+  native_type("DateTime2", NO_ARGUMENTS, &[ScalarType::DateTime]),
+  native_type("SmallDateTime", NO_ARGUMENTS, &[ScalarType::DateTime]),
+  native_type("DateTimeOffset", NO_ARGUMENTS, &[ScalarType::DateTime]),
+  native_type("Binary", OPTIONAL_ARGUMENT, &[ScalarType::Bytes]),
+  native_type("VarBinary", OPTIONAL_ARGUMENT, &[ScalarType::Bytes]),
+  native_type("Image", NO_ARGUMENTS, &[ScalarType::Bytes]),
+];
+
+const MONGODB_NATIVE_TYPES: &[NativeType] = &[
+  native_type("String", NO_ARGUMENTS, &[ScalarType::String]),
+  native_type("ObjectId", NO_ARGUMENTS, &[ScalarType::String, ScalarType::Bytes]),
+  native_type("Int", NO_ARGUMENTS, &[ScalarType::Int]),
+  native_type("Long", NO_ARGUMENTS, &[ScalarType::Int, ScalarType::BigInt]),
+  native_type("Double", NO_ARGUMENTS, &[ScalarType::Float]),
+  native_type("Bool", NO_ARGUMENTS, &[ScalarType::Boolean]),
+  native_type("Date", NO_ARGUMENTS, &[ScalarType::DateTime]),
+  native_type("Timestamp", NO_ARGUMENTS, &[ScalarType::DateTime]),
+  native_type("BinData", NO_ARGUMENTS, &[ScalarType::Bytes]),
+  native_type("Json", NO_ARGUMENTS, &[ScalarType::Json]),
+];
+
+const POSTGRES_PARAMS: &[&str] = &[
+  "schema",
+  "connection_limit",
+  "pool_timeout",
+  "connect_timeout",
+  "socket_timeout",
+  "max_connection_lifetime",
+  "max_idle_connection_lifetime",
+  "statement_cache_size",
+  "pgbouncer",
+  "sslmode",
+  "sslcert",
+  "sslidentity",
+  "sslpassword",
+  "sslaccept",
+  "application_name",
+  "channel_binding",
+  "options",
+  "host",
+];
+
+const MYSQL_PARAMS: &[&str] = &[
+  "connection_limit",
+  "pool_timeout",
+  "connect_timeout",
+  "socket_timeout",
+  "max_connection_lifetime",
+  "max_idle_connection_lifetime",
+  "statement_cache_size",
+  "sslcert",
+  "sslidentity",
+  "sslpassword",
+  "sslaccept",
+  "socket",
+];
+
+const SQLSERVER_PARAMS: &[&str] = &[
+  "database",
+  "initial catalog",
+  "user",
+  "username",
+  "uid",
+  "userid",
+  "password",
+  "pwd",
+  "schema",
+  "encrypt",
+  "trustServerCertificate",
+  "trustServerCertificateCA",
+  "integratedSecurity",
+  "authentication",
+  "applicationName",
+  "isolationLevel",
+  "connectionLimit",
+  "connectTimeout",
+  "poolTimeout",
+  "socketTimeout",
+];
+
+const SQLITE_PARAMS: &[&str] = &["connection_limit", "socket_timeout"];
+
+const MONGODB_PARAMS: &[&str] = &[
+  "appName",
+  "authMechanism",
+  "authMechanismProperties",
+  "authSource",
+  "compressors",
+  "connectTimeoutMS",
+  "directConnection",
+  "heartbeatFrequencyMS",
+  "journal",
+  "loadBalanced",
+  "localThresholdMS",
+  "maxIdleTimeMS",
+  "maxPoolSize",
+  "maxStalenessSeconds",
+  "minPoolSize",
+  "readConcernLevel",
+  "readPreference",
+  "readPreferenceTags",
+  "replicaSet",
+  "retryReads",
+  "retryWrites",
+  "serverSelectionTimeoutMS",
+  "socketTimeoutMS",
+  "ssl",
+  "tls",
+  "tlsAllowInvalidCertificates",
+  "tlsAllowInvalidHostnames",
+  "tlsCAFile",
+  "tlsCertificateKeyFile",
+  "tlsCertificateKeyFilePassword",
+  "tlsInsecure",
+  "w",
+  "wTimeoutMS",
+  "zlibCompressionLevel",
+];
+
+impl Provider {
+  pub fn info(&self) -> &'static ProviderInfo {
+    PROVIDERS.iter().find(|info| info.provider == *self).expect("Every provider is registered")
+  }
+
+  /// Name of the provider in the schema, e.g., "postgres".
+  pub fn name(&self) -> &'static str {
+    self.info().name
+  }
+
+  /// Find a native type of the provider by name, e.g., "VarChar".
+  pub fn native_type(&self, name: &str) -> Option<&'static NativeType> {
+    self.info().native_types.iter().find(|native_type| native_type.name == name)
+  }
+
+  /// Describe the names of all providers, e.g., for error messages:
+  /// one of "postgres", "cockroachdb", ... or "mongodb".
+  pub fn expected_names() -> &'static str {
+    static EXPECTED_NAMES: OnceLock<String> = OnceLock::new();
+    EXPECTED_NAMES.get_or_init(|| {
+      let names: Vec<String> = PROVIDERS.iter().map(|info| format!("\"{}\"", info.name)).collect();
+      match names.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("one of {} or {last}", rest.join(", ")),
+        None => String::new(),
+      }
+    })
+  }
+}
+
+/// Error of parsing a provider name that isn't registered.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct UnknownProviderError {
+  pub name: String,
+}
+
+impl fmt::Display for UnknownProviderError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "Unknown provider \"{}\", expected {}.", self.name, Provider::expected_names())
+  }
+}
+
+impl std::error::Error for UnknownProviderError {}
+
+impl FromStr for Provider {
+  type Err = UnknownProviderError;
+
+  fn from_str(name: &str) -> Result<Self, Self::Err> {
+    PROVIDERS
+      .iter()
+      .find(|info| info.name == name)
+      .map(|info| info.provider.clone())
+      .ok_or_else(|| UnknownProviderError { name: name.to_owned() })
+  }
+}
+
+impl TryFrom<&str> for Provider {
+  type Error = UnknownProviderError;
+
+  fn try_from(name: &str) -> Result<Self, Self::Error> {
+    name.parse()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_registry() {
+    for info in PROVIDERS {
+      assert_eq!(info.provider.info().name, info.name);
+      assert_eq!(info.name.parse(), Ok(info.provider.clone()));
+      assert_eq!(info.provider.to_string(), format!("\"{}\"", info.name));
+    }
+    assert_eq!(PROVIDERS.len(), 7);
+
+    let supported: Vec<&str> =
+      PROVIDERS.iter().filter(|info| info.supported).map(|info| info.name).collect();
+    assert_eq!(supported, vec!["postgres", "sqlite"]);
+  }
+
+  #[test]
+  fn test_capabilities() {
+    assert!(Provider::Postgres.info().capabilities.contains(Capabilities::SCALAR_LISTS));
+    assert!(!Provider::MySQL.info().capabilities.contains(Capabilities::SCALAR_LISTS));
+    assert!(Provider::SQLite.info().capabilities.is_empty());
+    assert_eq!(
+      Provider::SQLServer.info().capabilities.describe(),
+      vec!["native types", "multiple schemas"]
+    );
+  }
+
+  #[test]
+  fn test_native_types() {
+    for info in PROVIDERS {
+      let has_native_types = !info.native_types.is_empty();
+      assert_eq!(info.capabilities.contains(Capabilities::NATIVE_TYPES), has_native_types);
+    }
+
+    let uuid = Provider::Postgres.native_type("Uuid").unwrap();
+    assert_eq!(uuid.arguments, &[0]);
+    assert_eq!(uuid.scalar_types, &[ScalarType::String]);
+    assert!(Provider::CockroachDb.native_type("Uuid").is_some());
+    assert!(Provider::MySQL.native_type("Uuid").is_none());
+    assert_eq!(Provider::MySQL.native_type("VarChar").unwrap().arguments, &[1]);
+    assert_eq!(Provider::SQLite.native_type("Text").map(|native_type| native_type.name), None);
+  }
+
+  #[test]
+  fn test_unknown_provider() {
+    let error = Provider::try_from("oracle").unwrap_err();
+    assert_eq!(error, UnknownProviderError { name: String::from("oracle") });
+    assert_eq!(
+      error.to_string(),
+      concat!(
+        r#"Unknown provider "oracle", expected one of "postgres", "cockroachdb", "mysql", "#,
+        r#""mariadb", "sqlserver", "sqlite" or "mongodb"."#
+      )
+    );
+  }
+}
@@ -0,0 +1,352 @@
+use std::collections::BTreeMap;
+
+use crate::ast::{
+  composite_type::CompositeType,
+  datasource::Datasource,
+  datasource_db::DatasourceDb,
+  model::{Field, Model, ScalarType},
+  r#enum::Enum,
+  schema::SchemaAST,
+};
+
+use super::diagnostics::{did_you_mean, DatamodelError, Diagnostics, ErrorCode};
+
+/// Index of a model in `SchemaAST::models`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct ModelId(pub usize);
+
+/// Index of an enum in `SchemaAST::enums`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct EnumId(pub usize);
+
+/// Index of a composite type in `SchemaAST::composite_types`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct CompositeTypeId(pub usize);
+
+/// Index of a field in the fields of its model or composite type.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct FieldId(pub usize);
+
+/// A named top-level block that can be used as the type of a field.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TopId {
+  Model(ModelId),
+  Enum(EnumId),
+  CompositeType(CompositeTypeId),
+}
+
+/// What the type of a field refers to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FieldType {
+  Scalar(ScalarType),
+  Enum(EnumId),
+
+  /// Fields whose type is a model are relation fields.
+  Model(ModelId),
+  CompositeType(CompositeTypeId),
+}
+
+impl From<TopId> for FieldType {
+  fn from(id: TopId) -> Self {
+    match id {
+      TopId::Model(id) => Self::Model(id),
+      TopId::Enum(id) => Self::Enum(id),
+      TopId::CompositeType(id) => Self::CompositeType(id),
+    }
+  }
+}
+
+/// The schema with every name resolved, built once after parsing. Validation rules query it,
+/// rather than looking names up in the `SchemaAST` over and over again.
+///
+/// Top-level blocks are identified by their index in the `SchemaAST`. When a name is defined
+/// more than once, the first definition wins.
+pub struct ParserDatabase<'a> {
+  ast: &'a SchemaAST,
+  names: BTreeMap<&'a str, TopId>,
+
+  /// Types of the fields of each model, indexed by `ModelId` and `FieldId`.
+  /// Unknown types are None.
+  model_field_types: Vec<Vec<Option<FieldType>>>,
+
+  /// Types of the fields of each composite type, indexed by `CompositeTypeId` and `FieldId`.
+  /// Unknown types are None.
+  composite_type_field_types: Vec<Vec<Option<FieldType>>>,
+}
+
+impl<'a> ParserDatabase<'a> {
+  /// Resolve the names used in the given schema. Unknown field types are reported as errors.
+  pub fn new(ast: &'a SchemaAST, diagnostics: &mut Diagnostics) -> Self {
+    let mut names = BTreeMap::new();
+    let models =
+      ast.models.iter().enumerate().map(|(id, model)| (&model.name, TopId::Model(ModelId(id))));
+    let enums =
+      ast.enums.iter().enumerate().map(|(id, r#enum)| (&r#enum.name, TopId::Enum(EnumId(id))));
+    let composite_types = ast.composite_types.iter().enumerate().map(|(id, composite_type)| {
+      (&composite_type.name, TopId::CompositeType(CompositeTypeId(id)))
+    });
+    for (name, id) in models.chain(enums).chain(composite_types) {
+      names.entry(name.as_str()).or_insert(id);
+    }
+
+    let mut db = Self {
+      ast,
+      names,
+      model_field_types: vec![],
+      composite_type_field_types: vec![],
+    };
+    db.model_field_types =
+      ast.models.iter().map(|model| db.resolve_fields(&model.fields, diagnostics)).collect();
+    db.composite_type_field_types = ast
+      .composite_types
+      .iter()
+      .map(|composite_type| db.resolve_fields(&composite_type.fields, diagnostics))
+      .collect();
+    db
+  }
+
+  fn resolve_fields(
+    &self,
+    fields: &[Field],
+    diagnostics: &mut Diagnostics,
+  ) -> Vec<Option<FieldType>> {
+    fields
+      .iter()
+      .map(|field| {
+        let field_type = self.resolve_type(&field.field_type);
+        if field_type.is_none() {
+          diagnostics.push_error(self.unknown_type(field));
+        }
+        field_type
+      })
+      .collect()
+  }
+
+  fn resolve_type(&self, name: &str) -> Option<FieldType> {
+    match name.parse() {
+      Ok(scalar_type) => Some(FieldType::Scalar(scalar_type)),
+      Err(_) => self.find(name).map(FieldType::from),
+    }
+  }
+
+  fn unknown_type(&self, field: &Field) -> DatamodelError {
+    let name = &field.field_type;
+    let mut message = format!(
+      "The type \"{name}\" of the field \"{}\" is neither a scalar type, \
+       nor a model, an enum or a composite type.",
+      field.name
+    );
+    let scalar_types = ScalarType::ALL.iter().map(|scalar_type| scalar_type.name());
+    if let Some(suggestion) = did_you_mean(name, scalar_types.chain(self.names.keys().copied())) {
+      message.push_str(&format!(" Did you mean \"{suggestion}\"?"));
+    }
+    DatamodelError::new(ErrorCode::UnknownType, message, field.span)
+  }
+
+  pub fn ast(&self) -> &'a SchemaAST {
+    self.ast
+  }
+
+  /// The datasource whose provider the schema is validated against, i.e., the first one.
+  pub fn datasource(&self) -> Option<&'a DatasourceDb> {
+    self.ast.datasources.first().map(|datasource| match datasource {
+      Datasource::Db(datasource) => datasource,
+    })
+  }
+
+  /// Find the model, enum or composite type with the given name.
+  pub fn find(&self, name: &str) -> Option<TopId> {
+    self.names.get(name).copied()
+  }
+
+  pub fn find_model(&self, name: &str) -> Option<ModelId> {
+    match self.find(name) {
+      Some(TopId::Model(id)) => Some(id),
+      _ => None,
+    }
+  }
+
+  pub fn find_enum(&self, name: &str) -> Option<EnumId> {
+    match self.find(name) {
+      Some(TopId::Enum(id)) => Some(id),
+      _ => None,
+    }
+  }
+
+  pub fn find_composite_type(&self, name: &str) -> Option<CompositeTypeId> {
+    match self.find(name) {
+      Some(TopId::CompositeType(id)) => Some(id),
+      _ => None,
+    }
+  }
+
+  pub fn model(&self, id: ModelId) -> &'a Model {
+    &self.ast.models[id.0]
+  }
+
+  pub fn r#enum(&self, id: EnumId) -> &'a Enum {
+    &self.ast.enums[id.0]
+  }
+
+  pub fn composite_type(&self, id: CompositeTypeId) -> &'a CompositeType {
+    &self.ast.composite_types[id.0]
+  }
+
+  pub fn models(&self) -> impl Iterator<Item = (ModelId, &'a Model)> {
+    self.ast.models.iter().enumerate().map(|(id, model)| (ModelId(id), model))
+  }
+
+  pub fn enums(&self) -> impl Iterator<Item = (EnumId, &'a Enum)> {
+    self.ast.enums.iter().enumerate().map(|(id, r#enum)| (EnumId(id), r#enum))
+  }
+
+  pub fn composite_types(&self) -> impl Iterator<Item = (CompositeTypeId, &'a CompositeType)> {
+    let composite_types = self.ast.composite_types.iter().enumerate();
+    composite_types.map(|(id, composite_type)| (CompositeTypeId(id), composite_type))
+  }
+
+  /// Fields of the given model, with their resolved types. Unknown types are None.
+  pub fn model_fields(
+    &self,
+    id: ModelId,
+  ) -> impl Iterator<Item = (FieldId, &'a Field, Option<FieldType>)> + '_ {
+    let fields = self.model(id).fields.iter().zip(&self.model_field_types[id.0]);
+    fields.enumerate().map(|(index, (field, field_type))| (FieldId(index), field, *field_type))
+  }
+
+  /// Fields of the given composite type, with their resolved types. Unknown types are None.
+  pub fn composite_type_fields(
+    &self,
+    id: CompositeTypeId,
+  ) -> impl Iterator<Item = (FieldId, &'a Field, Option<FieldType>)> + '_ {
+    let fields =
+      self.composite_type(id).fields.iter().zip(&self.composite_type_field_types[id.0]);
+    fields.enumerate().map(|(index, (field, field_type))| (FieldId(index), field, *field_type))
+  }
+
+  /// Resolved type of a field of the given model, or None if it's unknown.
+  pub fn model_field_type(&self, model: ModelId, field: FieldId) -> Option<FieldType> {
+    self.model_field_types[model.0][field.0]
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::ast::span::Span;
+
+  const SCHEMA: &str = r#"
+    model User {
+      id      Int      @id
+      role    Role
+      address Address?
+      posts   Post[]
+    }
+
+    model Post {
+      id     Int  @id
+      author User
+    }
+
+    enum Role {
+      USER
+    }
+
+    type Address {
+      street String
+      role   Role
+    }
+  "#;
+
+  #[test]
+  fn test_resolve_names() {
+    let ast = crate::parse_schema(String::from(SCHEMA)).unwrap();
+    let mut diagnostics = Diagnostics::new();
+    let db = ParserDatabase::new(&ast, &mut diagnostics);
+    assert!(!diagnostics.has_errors());
+
+    assert_eq!(db.find("User"), Some(TopId::Model(ModelId(0))));
+    assert_eq!(db.find("Role"), Some(TopId::Enum(EnumId(0))));
+    assert_eq!(db.find("Address"), Some(TopId::CompositeType(CompositeTypeId(0))));
+    assert_eq!(db.find("String"), None);
+    assert_eq!(db.find_model("Post"), Some(ModelId(1)));
+    assert_eq!(db.find_model("Role"), None);
+    assert_eq!(db.find_enum("Role"), Some(EnumId(0)));
+    assert_eq!(db.find_composite_type("Address"), Some(CompositeTypeId(0)));
+
+    let user = db.find_model("User").unwrap();
+    let field_types: Vec<(&str, Option<FieldType>)> = db
+      .model_fields(user)
+      .map(|(_, field, field_type)| (field.name.as_str(), field_type))
+      .collect();
+    assert_eq!(
+      field_types,
+      vec![
+        ("id", Some(FieldType::Scalar(ScalarType::Int))),
+        ("role", Some(FieldType::Enum(EnumId(0)))),
+        ("address", Some(FieldType::CompositeType(CompositeTypeId(0)))),
+        ("posts", Some(FieldType::Model(ModelId(1)))),
+      ]
+    );
+    assert_eq!(db.model_field_type(ModelId(1), FieldId(1)), Some(FieldType::Model(user)));
+
+    let (address, _) = db.composite_types().next().unwrap();
+    let field_types: Vec<Option<FieldType>> =
+      db.composite_type_fields(address).map(|(_, _, field_type)| field_type).collect();
+    assert_eq!(
+      field_types,
+      vec![Some(FieldType::Scalar(ScalarType::String)), Some(FieldType::Enum(EnumId(0)))]
+    );
+  }
+
+  #[test]
+  fn test_unknown_types() {
+    let schema = SCHEMA
+      .replace("role    Role", "role    Rol")
+      .replace("street String", "street Strin")
+      .replace("author User", "author Author");
+    let ast = crate::parse_schema(schema.clone()).unwrap();
+    let mut diagnostics = Diagnostics::new();
+    let db = ParserDatabase::new(&ast, &mut diagnostics);
+
+    let errors: Vec<(ErrorCode, &str, Span)> = diagnostics
+      .errors()
+      .iter()
+      .map(|error| (error.code, error.message.as_str(), error.span))
+      .collect();
+    assert_eq!(
+      errors,
+      vec![
+        (
+          ErrorCode::UnknownType,
+          concat!(
+            r#"The type "Rol" of the field "role" is neither a scalar type, "#,
+            r#"nor a model, an enum or a composite type. Did you mean "Role"?"#
+          ),
+          Span::of(&schema, "role    Rol"),
+        ),
+        (
+          ErrorCode::UnknownType,
+          concat!(
+            r#"The type "Author" of the field "author" is neither a scalar type, "#,
+            "nor a model, an enum or a composite type."
+          ),
+          Span::of(&schema, "author Author"),
+        ),
+        (
+          ErrorCode::UnknownType,
+          concat!(
+            r#"The type "Strin" of the field "street" is neither a scalar type, "#,
+            r#"nor a model, an enum or a composite type. Did you mean "String"?"#
+          ),
+          Span::of(&schema, "street Strin"),
+        ),
+      ]
+    );
+
+    // fields with unknown types are kept
+    let user = db.find_model("User").unwrap();
+    assert_eq!(db.model_field_type(user, FieldId(1)), None);
+    assert_eq!(db.model_fields(user).count(), 4);
+  }
+}
@@ -0,0 +1,467 @@
+use crate::ast::{
+  attribute::{Attribute, Expression},
+  model::{Field, FieldArity, ScalarType},
+  span::{Span, Spanned},
+};
+use crate::providers::Capabilities;
+
+use super::db::{FieldType, ParserDatabase};
+use super::diagnostics::{did_you_mean, DatamodelError, Diagnostics, ErrorCode};
+
+/// Functions that generate default values, with the scalar types they return.
+/// Functions that return None, e.g., dbgenerated("..."), can be used on any scalar type.
+const DEFAULT_FUNCTIONS: &[(&str, Option<&[ScalarType]>)] = &[
+  ("autoincrement", Some(&[ScalarType::Int, ScalarType::BigInt])),
+  ("now", Some(&[ScalarType::DateTime])),
+  ("uuid", Some(&[ScalarType::String])),
+  ("cuid", Some(&[ScalarType::String])),
+  ("dbgenerated", None),
+];
+
+fn invalid_default(message: impl Into<String>, span: Span) -> DatamodelError {
+  DatamodelError::new(ErrorCode::InvalidDefaultValue, message, span)
+}
+
+/// Describe a default value, e.g., "a string" or "the number 3.5", for error messages.
+fn describe(value: &Expression) -> String {
+  match value {
+    Expression::StringValue { .. } => String::from("a string"),
+    Expression::NumericValue { value, .. } => format!("the number {value}"),
+    Expression::ConstantValue { value, .. } => format!("the constant {value}"),
+    Expression::Function(function_call) => format!("the function {}()", function_call.name),
+    Expression::Array { .. } => String::from("a list"),
+  }
+}
+
+/// Check that `@default(...)` values are compatible with the types of their fields:
+/// - functions, e.g., autoincrement(), return the type of the field
+/// - literals have the type of the field, and numbers fit into it
+/// - enum defaults are values of the enum
+/// - lists are only used on list fields, if the provider supports scalar lists
+pub fn validate_defaults(db: &ParserDatabase, diagnostics: &mut Diagnostics) {
+  let model_fields = db.models().flat_map(|(id, _)| db.model_fields(id));
+  let composite_type_fields =
+    db.composite_types().flat_map(|(id, _)| db.composite_type_fields(id));
+  for (_, field, field_type) in model_fields.chain(composite_type_fields) {
+    // fields of unknown types are already reported
+    let Some(field_type) = field_type else {
+      continue;
+    };
+    let defaults = field.attributes.iter().filter(|attribute| attribute.name == "default");
+    for attribute in defaults {
+      let default = DefaultValue { db, field, field_type };
+      if let Err(error) = default.validate(attribute) {
+        diagnostics.push_error(error);
+      }
+    }
+  }
+}
+
+/// A `@default(...)` attribute, together with the field it's defined on.
+struct DefaultValue<'a> {
+  db: &'a ParserDatabase<'a>,
+  field: &'a Field,
+  field_type: FieldType,
+}
+
+impl<'a> DefaultValue<'a> {
+  fn validate(&self, attribute: &Attribute) -> Result<(), DatamodelError> {
+    let value = attribute
+      .arguments
+      .iter()
+      .find(|argument| argument.name.is_none() || argument.name.as_deref() == Some("value"))
+      .map(|argument| &argument.value)
+      .ok_or_else(|| {
+        invalid_default(
+          format!("The @default attribute of the field \"{}\" needs a value.", self.field.name),
+          attribute.span,
+        )
+      })?;
+
+    match (value, self.field.arity == FieldArity::List) {
+      (Expression::Function(_), _) => self.validate_value(value),
+      (Expression::Array { value: values, span }, true) => {
+        self.validate_list_support(*span)?;
+        values.iter().try_for_each(|value| self.validate_value(value))
+      }
+      (Expression::Array { span, .. }, false) => Err(invalid_default(
+        format!(
+          "The default value of the field \"{}\" can't be a list, as the field isn't a list.",
+          self.field.name
+        ),
+        *span,
+      )),
+      (_, true) => Err(self.mismatch(&format!("a list of {}", self.field.field_type), value)),
+      (_, false) => self.validate_value(value),
+    }
+  }
+
+  fn validate_list_support(&self, span: Span) -> Result<(), DatamodelError> {
+    let Some(Spanned { value: provider, span: provider_span }) =
+      self.db.datasource().and_then(|datasource| datasource.provider.as_ref())
+    else {
+      return Ok(());
+    };
+    if provider.info().capabilities.contains(Capabilities::SCALAR_LISTS) {
+      return Ok(());
+    }
+    Err(
+      invalid_default(
+        format!(
+          "The default value of the field \"{}\" is a list, \
+           but the provider {provider} does not support scalar lists.",
+          self.field.name
+        ),
+        span,
+      )
+      .with_related_span("provider defined here", *provider_span),
+    )
+  }
+
+  fn mismatch(&self, expected: &str, value: &Expression) -> DatamodelError {
+    invalid_default(
+      format!(
+        "The default value of the field \"{}\" must be {expected}, but received {}.",
+        self.field.name,
+        describe(value)
+      ),
+      value.span(),
+    )
+  }
+
+  /// Check a single value, i.e., the default of a scalar or enum field, or an element of a list.
+  /// Relation and composite fields can't have any default value, not even dbgenerated().
+  fn validate_value(&self, value: &Expression) -> Result<(), DatamodelError> {
+    match (self.field_type, value) {
+      (FieldType::Model(_) | FieldType::CompositeType(_), _) => Err(invalid_default(
+        format!(
+          "The field \"{}\" can't have a default value, as its type {} is not a scalar or an enum.",
+          self.field.name, self.field.field_type
+        ),
+        value.span(),
+      )),
+      (_, Expression::Function(function_call)) => {
+        self.validate_function(&function_call.name, value.span())
+      }
+      (FieldType::Scalar(scalar_type), _) => self.validate_literal(scalar_type, value),
+      (FieldType::Enum(id), _) => {
+        let r#enum = self.db.r#enum(id);
+        let Expression::ConstantValue { value: name, span } = value else {
+          return Err(self.mismatch(&format!("a value of the enum {}", r#enum.name), value));
+        };
+        if r#enum.values.iter().any(|enum_value| enum_value.name == *name) {
+          return Ok(());
+        }
+        let mut message =
+          format!("The default value {name} is not a value of the enum \"{}\".", r#enum.name);
+        let names = r#enum.values.iter().map(|enum_value| enum_value.name.as_str());
+        if let Some(suggestion) = did_you_mean(name, names) {
+          message.push_str(&format!(" Did you mean {suggestion}?"));
+        }
+        Err(invalid_default(message, *span))
+      }
+    }
+  }
+
+  fn validate_function(&self, name: &str, span: Span) -> Result<(), DatamodelError> {
+    let Some((_, return_types)) = DEFAULT_FUNCTIONS.iter().find(|(function, _)| *function == name)
+    else {
+      let functions = DEFAULT_FUNCTIONS.iter().map(|(function, _)| *function);
+      let mut message = format!("The function {name}() can't be used as a default value.");
+      if let Some(suggestion) = did_you_mean(name, functions) {
+        message.push_str(&format!(" Did you mean {suggestion}()?"));
+      }
+      return Err(invalid_default(message, span));
+    };
+
+    // functions return a single value, except dbgenerated(), which returns whatever the database
+    // generates
+    if return_types.is_some() && self.field.arity == FieldArity::List {
+      return Err(invalid_default(
+        format!(
+          "The function {name}() can't be the default value of the field \"{}\", as it's a list.",
+          self.field.name
+        ),
+        span,
+      ));
+    }
+
+    let is_compatible = match (return_types, self.field_type) {
+      (None, _) => true,
+      (Some(return_types), FieldType::Scalar(scalar_type)) => return_types.contains(&scalar_type),
+      (Some(_), _) => false,
+    };
+    if is_compatible {
+      return Ok(());
+    }
+
+    let expected: Vec<&str> = return_types
+      .unwrap_or_default()
+      .iter()
+      .map(|scalar_type| scalar_type.name())
+      .collect();
+    Err(invalid_default(
+      format!(
+        "The function {name}() can't be the default value of the field \"{}\" of type {}, \
+         only of fields of type {}.",
+        self.field.name,
+        self.field.field_type,
+        expected.join(" or ")
+      ),
+      span,
+    ))
+  }
+
+  fn validate_literal(
+    &self,
+    scalar_type: ScalarType,
+    value: &Expression,
+  ) -> Result<(), DatamodelError> {
+    let expected = match scalar_type {
+      ScalarType::Int => "an Int, e.g., 42",
+      ScalarType::BigInt => "a BigInt, e.g., 42",
+      ScalarType::Float | ScalarType::Decimal => "a number, e.g., 3.14",
+      ScalarType::Boolean => "true or false",
+      _ => "a string",
+    };
+
+    let is_valid = match (scalar_type, value) {
+      (ScalarType::Int | ScalarType::BigInt, Expression::NumericValue { value: number, span }) => {
+        let digits = number.strip_prefix('-').unwrap_or(number);
+        if !digits.chars().all(|c| c.is_ascii_digit()) {
+          return Err(invalid_default(
+            format!(
+              "The default value {number} of the field \"{}\" is not an integer, \
+               as expected by the type {}.",
+              self.field.name, scalar_type
+            ),
+            *span,
+          ));
+        }
+        let fits = match scalar_type {
+          ScalarType::Int => number.parse::<i32>().is_ok(),
+          _ => number.parse::<i64>().is_ok(),
+        };
+        if !fits {
+          return Err(invalid_default(
+            format!(
+              "The default value {number} of the field \"{}\" doesn't fit into the type {}.",
+              self.field.name, scalar_type
+            ),
+            *span,
+          ));
+        }
+        true
+      }
+      (ScalarType::Float | ScalarType::Decimal, Expression::NumericValue { .. }) => true,
+      (ScalarType::Boolean, Expression::ConstantValue { value, .. }) => {
+        value == "true" || value == "false"
+      }
+      (
+        ScalarType::String
+        | ScalarType::DateTime
+        | ScalarType::Json
+        | ScalarType::Bytes,
+        Expression::StringValue { .. },
+      ) => true,
+      _ => false,
+    };
+
+    if is_valid {
+      Ok(())
+    } else {
+      Err(self.mismatch(expected, value))
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn validate_with_provider(provider: &str, url: &str, fields: &str) -> Vec<(String, Span)> {
+    let schema = format!(
+      "datasource db {{\n  provider = \"{provider}\"\n  url = \"{url}\"\n}}\n\
+       model User {{\n{fields}\n}}\n\
+       enum Role {{\n  USER\n  ADMIN\n}}\n\
+       enum Grade {{\n  A\n  B\n}}\n\
+       type Address {{\n  street String\n}}\n"
+    );
+    let ast = crate::parse_schema(schema).unwrap();
+    let mut diagnostics = Diagnostics::new();
+    let db = ParserDatabase::new(&ast, &mut diagnostics);
+    validate_defaults(&db, &mut diagnostics);
+    diagnostics
+      .errors()
+      .iter()
+      .map(|error| {
+        assert_eq!(error.code, ErrorCode::InvalidDefaultValue);
+        (error.message.clone(), error.span)
+      })
+      .collect()
+  }
+
+  fn validate(fields: &str) -> Vec<String> {
+    let errors = validate_with_provider("postgres", "postgres://localhost/db", fields);
+    errors.into_iter().map(|(message, _)| message).collect()
+  }
+
+  #[test]
+  fn test_valid_defaults() {
+    let fields = r#"
+      id        Int      @id @default(autoincrement())
+      big       BigInt   @default(9007199254740993)
+      uuid      String   @default(uuid())
+      cuid      String   @default(cuid())
+      name      String   @default("anonymous")
+      score     Float    @default(-1.5)
+      price     Decimal  @default(10)
+      active    Boolean  @default(true)
+      createdAt DateTime @default(now())
+      updatedAt DateTime @default("2022-10-18T00:00:00Z")
+      meta      Json     @default("{}")
+      role      Role     @default(ADMIN)
+      tags      String[] @default(["a", "b"])
+      roles     Role[]   @default([USER])
+      generated Int      @default(dbgenerated("random()"))
+      labels    String[] @default(dbgenerated("'{}'"))
+      thousand  Float    @default(1e3)
+      mapped    Int      @default(value: 1, map: "mapped_default")
+    "#;
+    assert_eq!(validate(fields), Vec::<String>::new());
+  }
+
+  #[test]
+  fn test_functions() {
+    let fields = r#"
+      id        String   @default(autoincrement())
+      createdAt String   @default(now())
+      uuid      Int      @default(uuid())
+      role      Role     @default(cuid())
+      random    Int      @default(random())
+      nowish    DateTime @default(noww())
+    "#;
+    assert_eq!(
+      validate(fields),
+      vec![
+        concat!(
+          r#"The function autoincrement() can't be the default value of the field "id" "#,
+          "of type String, only of fields of type Int or BigInt."
+        ),
+        concat!(
+          r#"The function now() can't be the default value of the field "createdAt" "#,
+          "of type String, only of fields of type DateTime."
+        ),
+        concat!(
+          r#"The function uuid() can't be the default value of the field "uuid" "#,
+          "of type Int, only of fields of type String."
+        ),
+        concat!(
+          r#"The function cuid() can't be the default value of the field "role" "#,
+          "of type Role, only of fields of type String."
+        ),
+        "The function random() can't be used as a default value.",
+        "The function noww() can't be used as a default value. Did you mean now()?",
+      ]
+    );
+
+    let fields = r#"
+      ids       String[]   @default(uuid())
+      dates     DateTime[] @default(now())
+      friend    User?      @default(dbgenerated("gen_random_uuid()"))
+      address   Address    @default(dbgenerated("'{}'"))
+    "#;
+    assert_eq!(
+      validate(fields),
+      vec![
+        r#"The function uuid() can't be the default value of the field "ids", as it's a list."#,
+        r#"The function now() can't be the default value of the field "dates", as it's a list."#,
+        concat!(
+          r#"The field "friend" can't have a default value, "#,
+          "as its type User is not a scalar or an enum."
+        ),
+        concat!(
+          r#"The field "address" can't have a default value, "#,
+          "as its type Address is not a scalar or an enum."
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_literals() {
+    let fields = r#"
+      name   String  @default(42)
+      count  Int     @default("42")
+      ratio  Int     @default(1.5)
+      big    Int     @default(3000000000)
+      active Boolean @default(yes)
+      role   Role    @default(ADMN)
+      guest  Role    @default("USER")
+      kilo   Int     @default(1e3)
+      grade  Grade   @default(C)
+    "#;
+    assert_eq!(
+      validate(fields),
+      vec![
+        r#"The default value of the field "name" must be a string, but received the number 42."#,
+        concat!(
+          r#"The default value of the field "count" must be an Int, e.g., 42, "#,
+          "but received a string."
+        ),
+        concat!(
+          r#"The default value 1.5 of the field "ratio" is not an integer, "#,
+          "as expected by the type Int."
+        ),
+        r#"The default value 3000000000 of the field "big" doesn't fit into the type Int."#,
+        concat!(
+          r#"The default value of the field "active" must be true or false, "#,
+          "but received the constant yes."
+        ),
+        r#"The default value ADMN is not a value of the enum "Role". Did you mean ADMIN?"#,
+        concat!(
+          r#"The default value of the field "guest" must be a value of the enum Role, "#,
+          "but received a string."
+        ),
+        concat!(
+          r#"The default value 1e3 of the field "kilo" is not an integer, "#,
+          "as expected by the type Int."
+        ),
+        r#"The default value C is not a value of the enum "Grade"."#,
+      ]
+    );
+  }
+
+  #[test]
+  fn test_lists() {
+    let fields = r#"
+      name  String   @default(["a"])
+      tags  String[] @default("a")
+      ids   Int[]    @default([1, "2"])
+    "#;
+    assert_eq!(
+      validate(fields),
+      vec![
+        r#"The default value of the field "name" can't be a list, as the field isn't a list."#,
+        r#"The default value of the field "tags" must be a list of String, but received a string."#,
+        concat!(
+          r#"The default value of the field "ids" must be an Int, e.g., 42, "#,
+          "but received a string."
+        ),
+      ]
+    );
+
+    let fields = r#"tags String[] @default(["a"])"#;
+    let errors = validate_with_provider("mysql", "mysql://localhost/db", fields);
+    assert_eq!(
+      errors,
+      vec![(
+        String::from(concat!(
+          r#"The default value of the field "tags" is a list, "#,
+          r#"but the provider "mysql" does not support scalar lists."#
+        )),
+        Span::new(106, 111),
+      )]
+    );
+  }
+}
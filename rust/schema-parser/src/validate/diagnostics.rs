@@ -1,15 +1,135 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
+use crate::ast::span::Span;
+
 #[cfg(feature = "wasm")]
 use tsify::Tsify;
 
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
 
-#[cfg_attr(feature = "wasm", tsify::declare)]
-type DatamodelError = String;
+/// Defines `ErrorCode` from a list of variants and their codes, so that the code of each variant,
+/// used both when serializing it and by `ErrorCode::as_str`, is only written once.
+macro_rules! error_codes {
+  ($($variant:ident => $code:literal,)*) => {
+    /// Stable identifier of an error or a warning, so that consumers don't need to match on
+    /// messages. Codes are never reused, even if the diagnostic they identify is removed.
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+    #[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+    pub enum ErrorCode {
+      $(
+        #[serde(rename = $code)]
+        $variant,
+      )*
+    }
+
+    impl ErrorCode {
+      pub fn as_str(&self) -> &'static str {
+        match self {
+          $(Self::$variant => $code,)*
+        }
+      }
+    }
+  };
+}
+
+error_codes! {
+  UnsupportedProvider => "E001_UNSUPPORTED_PROVIDER",
+  InvalidUrl => "E002_INVALID_URL",
+  UnknownProperty => "E003_UNKNOWN_PROPERTY",
+  DuplicateDatasource => "E004_DUPLICATE_DATASOURCE",
+  MultipleDatasources => "E005_MULTIPLE_DATASOURCES",
+  SyntaxError => "E006_SYNTAX_ERROR",
+  MissingProperty => "E007_MISSING_PROPERTY",
+  InvalidPropertyValue => "E008_INVALID_PROPERTY_VALUE",
+  EnvVarNotFound => "E009_ENV_VAR_NOT_FOUND",
+  UnknownUrlParameter => "E010_UNKNOWN_URL_PARAMETER",
+  UnsupportedFeature => "E011_UNSUPPORTED_FEATURE",
+  InvalidRelation => "E012_INVALID_RELATION",
+  InvalidReferentialAction => "E013_INVALID_REFERENTIAL_ACTION",
+  UnknownType => "E014_UNKNOWN_TYPE",
+  InvalidNativeType => "E015_INVALID_NATIVE_TYPE",
+  DuplicateName => "E016_DUPLICATE_NAME",
+  ReservedName => "E017_RESERVED_NAME",
+  InvalidDefaultValue => "E018_INVALID_DEFAULT_VALUE",
+  DuplicateProperty => "E019_DUPLICATE_PROPERTY",
+}
+
+impl fmt::Display for ErrorCode {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.as_str())
+  }
+}
+
+/// How serious a diagnostic is, so that errors and warnings can be told apart once they're
+/// merged into a single list, e.g., by editors.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub enum Severity {
+  Error,
+  Warning,
+}
+
+/// A secondary location that helps explaining an error,
+/// e.g., where a duplicate datasource was first defined.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub struct RelatedSpan {
+  pub message: String,
+  pub span: Span,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub struct DatamodelError {
+  /// Always `Severity::Error`, so that the TypeScript type is a discriminated union member.
+  #[cfg_attr(feature = "wasm", tsify(type = "\"error\""))]
+  pub severity: Severity,
+  pub code: ErrorCode,
+  pub message: String,
+
+  /// Location of the schema the error refers to.
+  pub span: Span,
+  pub related_spans: Vec<RelatedSpan>,
+}
+
+impl DatamodelError {
+  pub fn new(code: ErrorCode, message: impl Into<String>, span: Span) -> Self {
+    Self { severity: Severity::Error, code, message: message.into(), span, related_spans: vec![] }
+  }
+
+  /// Attach a secondary location to this error.
+  pub fn with_related_span(mut self, message: impl Into<String>, span: Span) -> Self {
+    self.related_spans.push(RelatedSpan { message: message.into(), span });
+    self
+  }
+}
+
+/// Something suspicious in the schema that doesn't prevent it from being used.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub struct DatamodelWarning {
+  /// Always `Severity::Warning`, so that the TypeScript type is a discriminated union member.
+  #[cfg_attr(feature = "wasm", tsify(type = "\"warning\""))]
+  pub severity: Severity,
+  pub code: ErrorCode,
+  pub message: String,
+  pub span: Span,
+}
+
+impl DatamodelWarning {
+  pub fn new(code: ErrorCode, message: impl Into<String>, span: Span) -> Self {
+    Self { severity: Severity::Warning, code, message: message.into(), span }
+  }
+}
 
-/// Represents a list of validation or parser errors.
+/// Represents a list of validation or parser errors and warnings.
 /// This is used to accumulate multiple errors and warnings during validation.
 /// It is used to not error out early and instead show multiple errors at once.
 #[derive(Debug, Serialize, Deserialize)]
@@ -17,31 +137,46 @@ type DatamodelError = String;
 #[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
 pub struct Diagnostics {
   errors: Vec<DatamodelError>,
+  warnings: Vec<DatamodelWarning>,
 }
 
 impl Diagnostics {
   pub fn new() -> Diagnostics {
-    Diagnostics { errors: Vec::new() }
+    Diagnostics { errors: Vec::new(), warnings: Vec::new() }
   }
 
   pub fn errors(&self) -> &[DatamodelError] {
     &self.errors
   }
 
+  pub fn warnings(&self) -> &[DatamodelWarning] {
+    &self.warnings
+  }
+
   pub fn push_error(&mut self, err: DatamodelError) {
     self.errors.push(err)
   }
 
+  pub fn push_warning(&mut self, warning: DatamodelWarning) {
+    self.warnings.push(warning)
+  }
+
   /// Returns true, if there is at least one error in this collection.
   pub fn has_errors(&self) -> bool {
     !self.errors.is_empty()
   }
 
-  pub fn to_result(&mut self) -> Result<(), Diagnostics> {
+  /// Returns true, if there is at least one warning in this collection.
+  pub fn has_warnings(&self) -> bool {
+    !self.warnings.is_empty()
+  }
+
+  /// Warnings alone don't make the result an error: without errors, they're returned on success.
+  pub fn to_result(&mut self) -> Result<Diagnostics, Diagnostics> {
     if self.has_errors() {
       Err(std::mem::take(self))
     } else {
-      Ok(())
+      Ok(std::mem::take(self))
     }
   }
 }
@@ -59,3 +194,63 @@ impl Default for Diagnostics {
     Self::new()
   }
 }
+
+/// Returns the candidate closest to the given name, if it's close enough for the name to likely be
+/// a typo of it, e.g., "model" for "modle". The allowed distance grows with the length of the
+/// candidate, so that short names, e.g., enum values like "A", get no arbitrary suggestions.
+pub(crate) fn did_you_mean<'a>(
+  name: &str,
+  candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+  candidates
+    .into_iter()
+    .map(|candidate| (strsim::damerau_levenshtein(name, candidate), candidate))
+    .filter(|(distance, candidate)| *distance > 0 && *distance * 3 <= candidate.len())
+    .min_by_key(|(distance, _)| *distance)
+    .map(|(_, candidate)| candidate)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_error_code_serialization() {
+    let code = ErrorCode::UnknownUrlParameter;
+    assert_eq!(code.as_str(), "E010_UNKNOWN_URL_PARAMETER");
+    let json = serde_json::to_string(&code).unwrap();
+    assert_eq!(json, r#""E010_UNKNOWN_URL_PARAMETER""#);
+    assert_eq!(serde_json::from_str::<ErrorCode>(&json).unwrap(), code);
+  }
+
+  #[test]
+  fn test_severity_serialization() {
+    let error = DatamodelError::new(ErrorCode::InvalidUrl, "invalid", Span::new(0, 1));
+    let json = serde_json::to_value(&error).unwrap();
+    assert_eq!(json["severity"], "error");
+    assert_eq!(json["code"], "E002_INVALID_URL");
+
+    let warning = DatamodelWarning::new(ErrorCode::UnknownUrlParameter, "unknown", Span::new(0, 1));
+    let json = serde_json::to_value(&warning).unwrap();
+    assert_eq!(json["severity"], "warning");
+  }
+
+  #[test]
+  fn test_did_you_mean() {
+    let keywords = ["datasource", "model", "enum", "generator"];
+    assert_eq!(did_you_mean("modle", keywords), Some("model"));
+    assert_eq!(did_you_mean("Model", keywords), Some("model"));
+    assert_eq!(did_you_mean("datsource", keywords), Some("datasource"));
+    assert_eq!(did_you_mean("genrator", keywords), Some("generator"));
+
+    // exact matches and unrelated names have no suggestion
+    assert_eq!(did_you_mean("model", keywords), None);
+    assert_eq!(did_you_mean("type", keywords), None);
+    assert_eq!(did_you_mean("}", keywords), None);
+
+    // short names are too different from each other for suggestions to be meaningful
+    assert_eq!(did_you_mean("C", ["A", "B"]), None);
+    assert_eq!(did_you_mean("OK", ["NO", "KO"]), None);
+    assert_eq!(did_you_mean("noww", ["now"]), Some("now"));
+  }
+}
@@ -0,0 +1,40 @@
+use std::collections::{BTreeMap, HashMap};
+
+/// Source of the environment variables referenced via env("...") in a schema, e.g., for database
+/// URLs. It's injected into validation, as the process environment isn't available everywhere,
+/// e.g., in Wasm.
+pub trait EnvProvider {
+  /// Returns the value of the given environment variable, or None if it isn't set.
+  fn var(&self, name: &str) -> Option<String>;
+}
+
+/// Reads the environment of the current process.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ProcessEnv;
+
+impl EnvProvider for ProcessEnv {
+  fn var(&self, name: &str) -> Option<String> {
+    std::env::var(name).ok()
+  }
+}
+
+impl EnvProvider for HashMap<String, String> {
+  fn var(&self, name: &str) -> Option<String> {
+    self.get(name).cloned()
+  }
+}
+
+impl EnvProvider for BTreeMap<String, String> {
+  fn var(&self, name: &str) -> Option<String> {
+    self.get(name).cloned()
+  }
+}
+
+impl<F> EnvProvider for F
+where
+  F: Fn(&str) -> Option<String>,
+{
+  fn var(&self, name: &str) -> Option<String> {
+    self(name)
+  }
+}
@@ -1,2 +1,9 @@
+pub mod connection_string;
+pub mod db;
+pub mod defaults;
 pub mod diagnostics;
+pub mod env;
+pub mod names;
+pub mod native_types;
+pub mod relations;
 pub mod validator;
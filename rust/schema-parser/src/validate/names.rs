@@ -0,0 +1,284 @@
+use std::collections::BTreeMap;
+
+use crate::ast::{
+  model::{Field, ScalarType},
+  schema::SchemaAST,
+  span::Span,
+};
+
+use super::diagnostics::{DatamodelError, Diagnostics, ErrorCode};
+
+/// Names of the generated client that models, enums and composite types can't use.
+const RESERVED_NAMES: &[&str] = &["PrismaClient", "Prisma"];
+
+/// Something with a name, e.g., a model or a field, and its kind, as shown in error messages.
+struct Named<'a> {
+  kind: &'static str,
+  name: &'a str,
+  span: Span,
+}
+
+/// Report every item whose name is already used by a previous item, in the order of their spans.
+/// `scope` describes where the names must be unique, e.g., ` in the model "User"`.
+fn validate_duplicates<'a>(
+  items: impl IntoIterator<Item = Named<'a>>,
+  scope: &str,
+  diagnostics: &mut Diagnostics,
+) {
+  let mut items: Vec<Named> = items.into_iter().collect();
+  items.sort_by_key(|item| item.span.start);
+
+  let mut first_definitions: BTreeMap<&str, &Named> = BTreeMap::new();
+  for item in &items {
+    let Some(first) = first_definitions.get(item.name) else {
+      first_definitions.insert(item.name, item);
+      continue;
+    };
+    let message = if first.kind == item.kind {
+      format!("The {} \"{}\" cannot be defined more than once{scope}.", item.kind, item.name)
+    } else {
+      format!(
+        "The {} \"{}\" cannot have the same name as the {} \"{}\"{scope}.",
+        item.kind, item.name, first.kind, first.name
+      )
+    };
+    diagnostics.push_error(
+      DatamodelError::new(ErrorCode::DuplicateName, message, item.span)
+        .with_related_span("first defined here", first.span),
+    );
+  }
+}
+
+fn fields<'a>(fields: &'a [Field]) -> impl Iterator<Item = Named<'a>> {
+  fields.iter().map(|field| Named { kind: "field", name: &field.name, span: field.span })
+}
+
+/// Check that models, enums, composite types and generators have unique names, which aren't
+/// reserved, and that fields and enum values are unique within their block.
+/// Duplicate datasources are reported by `validate_configuration`.
+pub fn validate_names(ast: &SchemaAST, diagnostics: &mut Diagnostics) {
+  // models, enums and composite types share the same namespace, as they are all field types
+  let models = ast.models.iter().map(|model| Named {
+    kind: "model",
+    name: &model.name,
+    span: model.span,
+  });
+  let enums =
+    ast.enums.iter().map(|r#enum| Named { kind: "enum", name: &r#enum.name, span: r#enum.span });
+  let composite_types = ast.composite_types.iter().map(|composite_type| Named {
+    kind: "composite type",
+    name: &composite_type.name,
+    span: composite_type.span,
+  });
+  let types: Vec<Named> = models.chain(enums).chain(composite_types).collect();
+
+  for Named { kind, name, span } in &types {
+    let message = if name.parse::<ScalarType>().is_ok() {
+      format!("The {kind} name \"{name}\" is reserved, as it's a built-in scalar type.")
+    } else if RESERVED_NAMES.contains(name) {
+      format!("The {kind} name \"{name}\" is reserved by the generated client.")
+    } else {
+      continue;
+    };
+    diagnostics.push_error(DatamodelError::new(ErrorCode::ReservedName, message, *span));
+  }
+  validate_duplicates(types, "", diagnostics);
+
+  let generators = ast.generators.iter().map(|generator| Named {
+    kind: "generator",
+    name: &generator.name,
+    span: generator.span,
+  });
+  validate_duplicates(generators, "", diagnostics);
+
+  for model in &ast.models {
+    let scope = format!(" in the model \"{}\"", model.name);
+    validate_duplicates(fields(&model.fields), &scope, diagnostics);
+  }
+
+  for composite_type in &ast.composite_types {
+    let scope = format!(" in the composite type \"{}\"", composite_type.name);
+    validate_duplicates(fields(&composite_type.fields), &scope, diagnostics);
+  }
+
+  for r#enum in &ast.enums {
+    let scope = format!(" in the enum \"{}\"", r#enum.name);
+    let values = r#enum.values.iter().map(|value| Named {
+      kind: "value",
+      name: &value.name,
+      span: value.span,
+    });
+    validate_duplicates(values, &scope, diagnostics);
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn validate(schema: &str) -> Vec<(ErrorCode, String, Span, Vec<Span>)> {
+    let ast = crate::parse_schema(String::from(schema)).unwrap();
+    let mut diagnostics = Diagnostics::new();
+    validate_names(&ast, &mut diagnostics);
+    diagnostics
+      .errors()
+      .iter()
+      .map(|error| {
+        let related_spans = error.related_spans.iter().map(|related| related.span).collect();
+        (error.code, error.message.clone(), error.span, related_spans)
+      })
+      .collect()
+  }
+
+  /// Span of the nth block, starting from the given keyword, e.g., "model User".
+  fn block_span(schema: &str, header: &str, nth: usize) -> Span {
+    let (start, _) = schema.match_indices(header).nth(nth).unwrap();
+    Span::new(start, start + schema[start..].find('}').unwrap() + 1)
+  }
+
+  #[test]
+  fn test_duplicate_blocks() {
+    let schema = r#"
+      generator client {
+        provider = "prisma-client-js"
+      }
+
+      model User {
+        id Int @id
+      }
+
+      enum User {
+        ADMIN
+      }
+
+      model User {
+        id Int @id
+      }
+
+      type Address {
+        street String
+      }
+
+      generator client {
+        provider = "prisma-client-js"
+      }
+    "#;
+
+    let model_span = block_span(schema, "model User", 0);
+    assert_eq!(
+      validate(schema),
+      vec![
+        (
+          ErrorCode::DuplicateName,
+          String::from(r#"The enum "User" cannot have the same name as the model "User"."#),
+          block_span(schema, "enum User", 0),
+          vec![model_span],
+        ),
+        (
+          ErrorCode::DuplicateName,
+          String::from(r#"The model "User" cannot be defined more than once."#),
+          block_span(schema, "model User", 1),
+          vec![model_span],
+        ),
+        (
+          ErrorCode::DuplicateName,
+          String::from(r#"The generator "client" cannot be defined more than once."#),
+          block_span(schema, "generator client", 1),
+          vec![block_span(schema, "generator client", 0)],
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_duplicate_fields_and_values() {
+    let schema = r#"
+      model User {
+        id    Int    @id
+        email String
+        email String?
+      }
+
+      type Address {
+        street String
+        street String
+      }
+
+      enum Role {
+        USER
+        ADMIN
+        USER
+      }
+    "#;
+
+    assert_eq!(
+      validate(schema),
+      vec![
+        (
+          ErrorCode::DuplicateName,
+          String::from(concat!(
+            r#"The field "email" cannot be defined more than once "#,
+            r#"in the model "User"."#
+          )),
+          Span::of(schema, "email String?"),
+          vec![Span::of(schema, "email String")],
+        ),
+        (
+          ErrorCode::DuplicateName,
+          String::from(concat!(
+            r#"The field "street" cannot be defined more than once "#,
+            r#"in the composite type "Address"."#
+          )),
+          Span::new(schema.rfind("street String").unwrap(), schema.rfind("String").unwrap() + 6),
+          vec![Span::of(schema, "street String")],
+        ),
+        (
+          ErrorCode::DuplicateName,
+          String::from(r#"The value "USER" cannot be defined more than once in the enum "Role"."#),
+          Span::new(schema.rfind("USER").unwrap(), schema.rfind("USER").unwrap() + 4),
+          vec![Span::of(schema, "USER")],
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_reserved_names() {
+    let schema = r#"
+      model String {
+        id Int @id
+      }
+
+      enum PrismaClient {
+        A
+      }
+
+      type DateTime {
+        value Int
+      }
+    "#;
+
+    let errors: Vec<(ErrorCode, String)> =
+      validate(schema).into_iter().map(|(code, message, _, _)| (code, message)).collect();
+    assert_eq!(
+      errors,
+      vec![
+        (
+          ErrorCode::ReservedName,
+          String::from(r#"The model name "String" is reserved, as it's a built-in scalar type."#),
+        ),
+        (
+          ErrorCode::ReservedName,
+          String::from(r#"The enum name "PrismaClient" is reserved by the generated client."#),
+        ),
+        (
+          ErrorCode::ReservedName,
+          String::from(concat!(
+            r#"The composite type name "DateTime" is reserved, "#,
+            "as it's a built-in scalar type."
+          )),
+        ),
+      ]
+    );
+  }
+}
@@ -0,0 +1,307 @@
+use crate::ast::{
+  attribute::{Attribute, Expression},
+  datasource_db::{DatasourceDb, Provider},
+  model::Field,
+  span::{Span, Spanned},
+};
+use crate::providers::{Capabilities, NativeType};
+
+use super::db::{FieldType, ParserDatabase};
+use super::diagnostics::{did_you_mean, DatamodelError, Diagnostics, ErrorCode};
+
+fn invalid_native_type(message: impl Into<String>, span: Span) -> DatamodelError {
+  DatamodelError::new(ErrorCode::InvalidNativeType, message, span)
+}
+
+/// Describe the accepted numbers of arguments of a native type, e.g., "0 or 1 arguments".
+fn describe_arguments(arguments: &[usize]) -> String {
+  let counts: Vec<String> = arguments.iter().map(usize::to_string).collect();
+  let noun = match arguments {
+    [1] => "argument",
+    _ => "arguments",
+  };
+  format!("{} {noun}", counts.join(" or "))
+}
+
+/// Check that the native type attributes of the fields, e.g., `@db.VarChar(255)`, are defined by
+/// the provider of the datasource, are used on fields of a compatible type, and get the right
+/// number of arguments.
+/// Nothing is checked if there's no datasource, if its provider is missing, or if its provider
+/// doesn't support native types, which is reported by `validate_capabilities`.
+pub fn validate_native_types(db: &ParserDatabase, diagnostics: &mut Diagnostics) {
+  let Some(datasource) = db.datasource() else {
+    return;
+  };
+  let Some(Spanned { value: provider, .. }) = &datasource.provider else {
+    return;
+  };
+  if !provider.info().capabilities.contains(Capabilities::NATIVE_TYPES) {
+    return;
+  }
+
+  let model_fields = db.models().flat_map(|(id, _)| db.model_fields(id));
+  let composite_type_fields =
+    db.composite_types().flat_map(|(id, _)| db.composite_type_fields(id));
+  for (_, field, field_type) in model_fields.chain(composite_type_fields) {
+    let mut first_native_type: Option<&Attribute> = None;
+    for attribute in &field.attributes {
+      let Some((prefix, name)) = attribute.name.split_once('.') else {
+        continue;
+      };
+      if let Some(first) = first_native_type {
+        let message =
+          format!("The field \"{}\" can't have more than one native type attribute.", field.name);
+        let error = invalid_native_type(message, attribute.span)
+          .with_related_span("first native type attribute defined here", first.span);
+        diagnostics.push_error(error);
+        continue;
+      }
+      first_native_type = Some(attribute);
+
+      let native_type = NativeTypeAttribute { datasource, provider, field, field_type, attribute };
+      if let Err(error) = native_type.validate(prefix, name) {
+        diagnostics.push_error(error);
+      }
+    }
+  }
+}
+
+/// A native type attribute, e.g., `@db.VarChar(255)`, together with the field it's defined on.
+struct NativeTypeAttribute<'a> {
+  datasource: &'a DatasourceDb,
+  provider: &'a Provider,
+  field: &'a Field,
+  field_type: Option<FieldType>,
+  attribute: &'a Attribute,
+}
+
+impl<'a> NativeTypeAttribute<'a> {
+  fn validate(&self, prefix: &str, name: &str) -> Result<(), DatamodelError> {
+    let datasource_name = &self.datasource.name;
+    if prefix != datasource_name {
+      return Err(invalid_native_type(
+        format!(
+          "The native type attribute \"@{}\" must be prefixed by the name of the datasource, \
+           i.e., \"@{datasource_name}.{name}\".",
+          self.attribute.name
+        ),
+        self.attribute.span,
+      ));
+    }
+
+    let native_type = self.find_native_type(name)?;
+    self.validate_field_type(native_type)?;
+    self.validate_arguments(native_type)
+  }
+
+  fn find_native_type(&self, name: &str) -> Result<&'static NativeType, DatamodelError> {
+    let provider = self.provider;
+    provider.native_type(name).ok_or_else(|| {
+      let mut message =
+        format!("The native type \"{name}\" is not supported by the provider {provider}.");
+      let names = provider.info().native_types.iter().map(|native_type| native_type.name);
+      if let Some(suggestion) = did_you_mean(name, names) {
+        message.push_str(&format!(" Did you mean \"{suggestion}\"?"));
+      }
+      invalid_native_type(message, self.attribute.span)
+    })
+  }
+
+  /// Native types only apply to some scalar types, e.g., `Uuid` to `String`.
+  /// Fields of unknown types are already reported, so they are skipped.
+  fn validate_field_type(&self, native_type: &NativeType) -> Result<(), DatamodelError> {
+    let is_compatible = match self.field_type {
+      Some(FieldType::Scalar(scalar_type)) => native_type.scalar_types.contains(&scalar_type),
+      Some(_) => false,
+      None => true,
+    };
+    if is_compatible {
+      return Ok(());
+    }
+
+    let scalar_types: Vec<&str> =
+      native_type.scalar_types.iter().map(|scalar_type| scalar_type.name()).collect();
+    Err(invalid_native_type(
+      format!(
+        "The native type \"{}\" can't be used on the field \"{}\" of type {}, \
+         only on fields of type {}.",
+        native_type.name,
+        self.field.name,
+        self.field.field_type,
+        scalar_types.join(" or ")
+      ),
+      self.attribute.span,
+    ))
+  }
+
+  fn validate_arguments(&self, native_type: &NativeType) -> Result<(), DatamodelError> {
+    let arguments = &self.attribute.arguments;
+    if !native_type.arguments.contains(&arguments.len()) {
+      return Err(invalid_native_type(
+        format!(
+          "The native type \"{}\" expects {}, but received {}.",
+          native_type.name,
+          describe_arguments(native_type.arguments),
+          arguments.len()
+        ),
+        self.attribute.span,
+      ));
+    }
+
+    for argument in arguments {
+      let is_integer = matches!(
+        &argument.value,
+        Expression::NumericValue { value, .. } if value.chars().all(|c| c.is_ascii_digit())
+      );
+      if argument.name.is_some() || !is_integer {
+        return Err(invalid_native_type(
+          format!(
+            "The arguments of the native type \"{}\" must be positive integers.",
+            native_type.name
+          ),
+          argument.span,
+        ));
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  /// Validate a schema with the given datasource and fields in a model, returning the schema
+  /// together with its diagnostics.
+  fn validate_schema(provider: &str, url: &str, fields: &str) -> (String, Diagnostics) {
+    let schema = format!(
+      "datasource db {{\n  provider = \"{provider}\"\n  url = \"{url}\"\n}}\n\
+       model User {{\n{fields}\n}}\n"
+    );
+    let ast = crate::parse_schema(schema.clone()).unwrap();
+    let mut diagnostics = Diagnostics::new();
+    let db = ParserDatabase::new(&ast, &mut diagnostics);
+    validate_native_types(&db, &mut diagnostics);
+    (schema, diagnostics)
+  }
+
+  fn validate(provider: &str, url: &str, fields: &str) -> Vec<(String, Span)> {
+    let (_, diagnostics) = validate_schema(provider, url, fields);
+    diagnostics
+      .errors()
+      .iter()
+      .map(|error| {
+        assert_eq!(error.code, ErrorCode::InvalidNativeType);
+        (error.message.clone(), error.span)
+      })
+      .collect()
+  }
+
+  fn validate_postgres(fields: &str) -> Vec<String> {
+    let errors = validate("postgres", "postgres://localhost/db", fields);
+    errors.into_iter().map(|(message, _)| message).collect()
+  }
+
+  #[test]
+  fn test_valid_native_types() {
+    let fields = r#"
+      id      String  @id @db.Uuid
+      name    String  @db.VarChar(255)
+      bio     String  @db.Text
+      balance Decimal @db.Decimal(10, 2)
+      amount  Decimal @db.Decimal
+      born    DateTime @db.Timestamptz(3)
+    "#;
+    assert_eq!(validate_postgres(fields), Vec::<String>::new());
+
+    let fields = r#"
+      id     String @id @db.Uuid
+      name   String @db.String(255)
+    "#;
+    assert_eq!(validate("cockroachdb", "postgres://localhost/db", fields), vec![]);
+
+    let fields = r#"
+      name   String  @db.VarChar(191)
+      active Boolean @db.TinyInt
+    "#;
+    assert_eq!(validate("mysql", "mysql://localhost/db", fields), vec![]);
+  }
+
+  #[test]
+  fn test_invalid_native_types() {
+    let fields = r#"
+      id    Int    @id @db.Uuid
+      name  String @db.VarChar(10, 20)
+      email String @db.VarChar("255")
+      bio   String @db.Txt
+    "#;
+    assert_eq!(
+      validate_postgres(fields),
+      vec![
+        concat!(
+          r#"The native type "Uuid" can't be used on the field "id" of type Int, "#,
+          "only on fields of type String."
+        ),
+        r#"The native type "VarChar" expects 0 or 1 arguments, but received 2."#,
+        r#"The arguments of the native type "VarChar" must be positive integers."#,
+        concat!(
+          r#"The native type "Txt" is not supported by the provider "postgres". "#,
+          r#"Did you mean "Text"?"#
+        ),
+      ]
+    );
+
+    // Uuid is specific to Postgres and CockroachDB
+    let fields = "id String @id @db.Uuid";
+    let errors = validate("mysql", "mysql://localhost/db", fields);
+    assert_eq!(
+      errors,
+      vec![(
+        String::from(r#"The native type "Uuid" is not supported by the provider "mysql"."#),
+        Span::new(97, 105),
+      )]
+    );
+
+    // VarChar needs a length in MySQL
+    let fields = "name String @db.VarChar";
+    let errors = validate("mysql", "mysql://localhost/db", fields);
+    assert_eq!(
+      errors[0].0,
+      r#"The native type "VarChar" expects 1 argument, but received 0."#
+    );
+  }
+
+  #[test]
+  fn test_multiple_native_types() {
+    let fields = "name String @db.VarChar(10) @unique @db.Text";
+    let (schema, diagnostics) = validate_schema("postgres", "postgres://localhost/db", fields);
+
+    assert_eq!(
+      diagnostics.errors(),
+      &[invalid_native_type(
+        r#"The field "name" can't have more than one native type attribute."#,
+        Span::of(&schema, "@db.Text"),
+      )
+      .with_related_span(
+        "first native type attribute defined here",
+        Span::of(&schema, "@db.VarChar(10)")
+      )]
+    );
+  }
+
+  #[test]
+  fn test_datasource_prefix() {
+    let fields = "name String @pg.VarChar(255)";
+    assert_eq!(
+      validate_postgres(fields),
+      vec![concat!(
+        r#"The native type attribute "@pg.VarChar" must be prefixed by the name of the "#,
+        r#"datasource, i.e., "@db.VarChar"."#
+      )]
+    );
+
+    // providers without native types are reported by validate_capabilities
+    assert_eq!(validate("sqlite", "file:./dev.db", fields), vec![]);
+  }
+}
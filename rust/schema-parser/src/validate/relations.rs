@@ -0,0 +1,983 @@
+use crate::ast::{
+  attribute::{Argument, Attribute, Expression},
+  datasource_db::RelationMode,
+  model::{Field, FieldArity, Model, ReferentialAction},
+  span::{Span, Spanned},
+};
+
+use crate::providers::EMULATED_REFERENTIAL_ACTIONS;
+
+use super::db::{FieldType, ModelId, ParserDatabase};
+use super::diagnostics::{did_you_mean, DatamodelError, Diagnostics, ErrorCode};
+
+/// A field listed in `fields` or `references`, e.g., authorId in `fields: [authorId]`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FieldReference<'a> {
+  pub name: &'a str,
+  pub span: Span,
+}
+
+/// A referential action, together with its span.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ReferentialActionValue {
+  pub action: ReferentialAction,
+  pub span: Span,
+}
+
+/// Typed view of a `@relation` attribute, e.g.,
+/// @relation("PostAuthor", fields: [authorId], references: [id], onDelete: Cascade).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct RelationAttribute<'a> {
+  /// Name that tells relations between the same models apart.
+  pub name: Option<&'a str>,
+  pub fields: Option<Vec<FieldReference<'a>>>,
+  pub references: Option<Vec<FieldReference<'a>>>,
+  pub on_delete: Option<ReferentialActionValue>,
+  pub on_update: Option<ReferentialActionValue>,
+
+  /// Name of the foreign key in the database.
+  pub map: Option<&'a str>,
+  pub span: Span,
+}
+
+fn invalid_relation(message: impl Into<String>, span: Span) -> DatamodelError {
+  DatamodelError::new(ErrorCode::InvalidRelation, message, span)
+}
+
+impl<'a> RelationAttribute<'a> {
+  /// Interpret the arguments of a `@relation` attribute. Invalid arguments are reported and
+  /// skipped, so that every problem in the attribute is reported at once.
+  pub fn from_attribute(attribute: &'a Attribute, diagnostics: &mut Diagnostics) -> Self {
+    let mut relation = Self { span: attribute.span, ..Self::default() };
+
+    for (index, Argument { name, value, span }) in attribute.arguments.iter().enumerate() {
+      let result = match (name.as_deref(), value) {
+        (None, Expression::StringValue { value, .. }) if index == 0 => {
+          relation.name = Some(value);
+          Ok(())
+        }
+        (Some("name"), Expression::StringValue { value, .. }) => {
+          relation.name = Some(value);
+          Ok(())
+        }
+        (Some("map"), Expression::StringValue { value, .. }) => {
+          relation.map = Some(value);
+          Ok(())
+        }
+        (Some("fields"), value) => {
+          Self::field_references(value).map(|fields| relation.fields = Some(fields))
+        }
+        (Some("references"), value) => {
+          Self::field_references(value).map(|references| relation.references = Some(references))
+        }
+        (Some("onDelete"), value) => {
+          Self::referential_action(value).map(|action| relation.on_delete = Some(action))
+        }
+        (Some("onUpdate"), value) => {
+          Self::referential_action(value).map(|action| relation.on_update = Some(action))
+        }
+        (Some(name @ ("name" | "map")), _) => Err(invalid_relation(
+          format!("The argument \"{name}\" of @relation must be a string."),
+          *span,
+        )),
+        (Some(name), _) => Err(invalid_relation(
+          format!("The argument \"{name}\" is not supported by @relation."),
+          *span,
+        )),
+        (None, _) => Err(invalid_relation(
+          "Only the name of a relation can be given as a positional argument of @relation.",
+          *span,
+        )),
+      };
+
+      if let Err(error) = result {
+        diagnostics.push_error(error);
+      }
+    }
+
+    relation
+  }
+
+  /// A list of field names, e.g., [authorId, authorEmail].
+  fn field_references(value: &'a Expression) -> Result<Vec<FieldReference<'a>>, DatamodelError> {
+    let invalid = || invalid_relation("Expected a list of field names, e.g., [id].", value.span());
+    match value {
+      Expression::Array { value, .. } => value
+        .iter()
+        .map(|element| match element {
+          Expression::ConstantValue { value, span } => {
+            Ok(FieldReference { name: value, span: *span })
+          }
+          _ => Err(invalid()),
+        })
+        .collect(),
+      _ => Err(invalid()),
+    }
+  }
+
+  fn referential_action(value: &Expression) -> Result<ReferentialActionValue, DatamodelError> {
+    match value {
+      Expression::ConstantValue { value, span } => match value.parse() {
+        Ok(action) => Ok(ReferentialActionValue { action, span: *span }),
+        Err(_) => Err(DatamodelError::new(
+          ErrorCode::InvalidReferentialAction,
+          format!(
+            "Unknown referential action \"{value}\", expected one of: {}.",
+            ReferentialAction::ALL.iter().map(|action| action.name()).collect::<Vec<_>>().join(", ")
+          ),
+          *span,
+        )),
+      },
+      _ => Err(invalid_relation("Expected a referential action, e.g., Cascade.", value.span())),
+    }
+  }
+}
+
+/// A relation field, i.e., a field whose type is a model, with its `@relation` attribute, if any.
+struct RelationField<'a> {
+  model_id: ModelId,
+  model: &'a Model,
+  field: &'a Field,
+  target_id: ModelId,
+  target: &'a Model,
+  relation: Option<RelationAttribute<'a>>,
+
+  /// Whether the `@relation` attribute has invalid arguments. Such fields are still used to
+  /// resolve opposite relation fields, but only their valid referential actions are validated.
+  is_invalid: bool,
+}
+
+impl<'a> RelationField<'a> {
+  fn name(&self) -> Option<&'a str> {
+    self.relation.as_ref().and_then(|relation| relation.name)
+  }
+
+  fn has_fields(&self) -> bool {
+    self
+      .relation
+      .as_ref()
+      .is_some_and(|relation| relation.fields.is_some() || relation.references.is_some())
+  }
+
+  /// Returns true if both fields are on the same side of a relation with the given name.
+  fn is_same_side(&self, other: &RelationField) -> bool {
+    self.model.name == other.model.name
+      && self.target.name == other.target.name
+      && self.name() == other.name()
+      && !std::ptr::eq(self.field, other.field)
+  }
+
+  /// Returns true if the given field can be on the other side of this relation.
+  fn is_opposite(&self, other: &RelationField) -> bool {
+    self.model.name == other.target.name
+      && self.target.name == other.model.name
+      && self.name() == other.name()
+      && !std::ptr::eq(self.field, other.field)
+  }
+
+  fn describe(&self) -> String {
+    format!("The relation field \"{}\" of the model \"{}\"", self.field.name, self.model.name)
+  }
+}
+
+/// Resolve both sides of every relation, and check that:
+/// - each relation field has exactly one opposite relation field
+/// - exactly one side of one-to-one and one-to-many relations defines `fields` and `references`
+/// - `fields` and `references` exist, have the same number of fields and the same types
+/// - the referenced fields are unique
+/// - referential actions are supported by the provider and the relation mode
+pub fn validate_relations(db: &ParserDatabase, diagnostics: &mut Diagnostics) {
+  let mut relation_fields = vec![];
+  for (model_id, model) in db.models() {
+    for (_, field, field_type) in db.model_fields(model_id) {
+      let Some(FieldType::Model(target_id)) = field_type else {
+        continue;
+      };
+      let target = db.model(target_id);
+      let attribute = field.attributes.iter().find(|attribute| attribute.name == "relation");
+      let error_count = diagnostics.errors().len();
+      let relation =
+        attribute.map(|attribute| RelationAttribute::from_attribute(attribute, diagnostics));
+      let is_invalid = diagnostics.errors().len() > error_count;
+      relation_fields.push(RelationField {
+        model_id,
+        model,
+        field,
+        target_id,
+        target,
+        relation,
+        is_invalid,
+      });
+    }
+  }
+
+  for relation_field in &relation_fields {
+    if relation_field.is_invalid {
+      if let Some(relation) = &relation_field.relation {
+        validate_referential_actions(db, relation_field, relation, diagnostics);
+      }
+      continue;
+    }
+
+    // in self-relations, both sides are on the same model, so a single relation has two fields
+    // with the same name, and any other field with that name makes it ambiguous
+    let is_self_relation = relation_field.model.name == relation_field.target.name;
+    let same_side_count =
+      relation_fields.iter().filter(|other| relation_field.is_same_side(other)).count();
+    let is_ambiguous = same_side_count > usize::from(is_self_relation);
+    let opposites: Vec<&RelationField> =
+      relation_fields.iter().filter(|other| relation_field.is_opposite(other)).collect();
+
+    match opposites.as_slice() {
+      _ if is_ambiguous && is_self_relation => diagnostics.push_error(invalid_relation(
+        format!(
+          "{} is ambiguous, as the model \"{}\" has more than two relation fields to itself. \
+           Give both sides of each relation the same name, e.g., @relation(\"UserFollows\").",
+          relation_field.describe(),
+          relation_field.model.name
+        ),
+        relation_field.field.span,
+      )),
+      _ if is_ambiguous => diagnostics.push_error(invalid_relation(
+        format!(
+          "{} is ambiguous, as the model \"{}\" has another relation field to the model \"{}\". \
+           Give each relation a different name, e.g., @relation(\"PostAuthor\").",
+          relation_field.describe(),
+          relation_field.model.name,
+          relation_field.target.name
+        ),
+        relation_field.field.span,
+      )),
+      [] => diagnostics.push_error(invalid_relation(
+        format!(
+          "{} is missing an opposite relation field on the model \"{}\".",
+          relation_field.describe(),
+          relation_field.target.name
+        ),
+        relation_field.field.span,
+      )),
+      [opposite] if !opposite.is_invalid => {
+        validate_relation_sides(relation_field, opposite, diagnostics)
+      }
+      [_] => {}
+      [_, ..] => diagnostics.push_error(invalid_relation(
+        format!(
+          "{} is ambiguous, as the model \"{}\" has more than one opposite relation field. \
+           Give both sides of each relation the same name, e.g., @relation(\"PostAuthor\").",
+          relation_field.describe(),
+          relation_field.target.name
+        ),
+        relation_field.field.span,
+      )),
+    }
+
+    if let Some(relation) = &relation_field.relation {
+      validate_fields_and_references(db, relation_field, relation, diagnostics);
+      validate_referential_actions(db, relation_field, relation, diagnostics);
+    }
+  }
+}
+
+/// Check which side of a relation defines `fields` and `references`.
+/// Errors about both sides are reported once, on the side defined last.
+fn validate_relation_sides(
+  relation_field: &RelationField,
+  opposite: &RelationField,
+  diagnostics: &mut Diagnostics,
+) {
+  let is_list = relation_field.field.arity == FieldArity::List;
+  let is_opposite_list = opposite.field.arity == FieldArity::List;
+  let is_defined_last = relation_field.field.span.start > opposite.field.span.start;
+
+  if is_list && relation_field.has_fields() {
+    diagnostics.push_error(invalid_relation(
+      format!(
+        "{} is a list, so it can't define `fields` and `references`.",
+        relation_field.describe()
+      ),
+      relation_field.field.span,
+    ));
+  } else if !is_list && is_opposite_list && !relation_field.has_fields() {
+    diagnostics.push_error(invalid_relation(
+      format!("{} must define `fields` and `references`.", relation_field.describe()),
+      relation_field.field.span,
+    ));
+  } else if !is_list && !is_opposite_list && is_defined_last {
+    match (relation_field.has_fields(), opposite.has_fields()) {
+      (false, false) => diagnostics.push_error(invalid_relation(
+        format!(
+          "{} is part of a one-to-one relation, \
+           so one of its sides must define `fields` and `references`.",
+          relation_field.describe()
+        ),
+        relation_field.field.span,
+      )),
+      (true, true) => diagnostics.push_error(invalid_relation(
+        format!(
+          "{} is part of a one-to-one relation, \
+           so only one of its sides can define `fields` and `references`.",
+          relation_field.describe()
+        ),
+        relation_field.field.span,
+      )),
+      _ => {}
+    }
+  }
+}
+
+/// Find the field with the given name in a model, or report it as missing.
+/// Only scalar fields are suggested, as relation fields can't be referenced.
+fn find_field<'a>(
+  db: &ParserDatabase<'a>,
+  model_id: ModelId,
+  reference: &FieldReference,
+  diagnostics: &mut Diagnostics,
+) -> Option<&'a Field> {
+  let model = db.model(model_id);
+  let field = model.fields.iter().find(|field| field.name == reference.name);
+  if field.is_none() {
+    let mut message =
+      format!("The field \"{}\" doesn't exist in the model \"{}\".", reference.name, model.name);
+    let names = db
+      .model_fields(model_id)
+      .filter(|(_, _, field_type)| !matches!(field_type, Some(FieldType::Model(_))))
+      .map(|(_, field, _)| field.name.as_str());
+    if let Some(suggestion) = did_you_mean(reference.name, names) {
+      message.push_str(&format!(" Did you mean \"{suggestion}\"?"));
+    }
+    diagnostics.push_error(invalid_relation(message, reference.span));
+  }
+  field
+}
+
+/// Returns true if the given fields are the fields of a `@@id` or `@@unique` attribute.
+fn is_unique_constraint(attribute: &Attribute, fields: &[&str]) -> bool {
+  if attribute.name != "id" && attribute.name != "unique" {
+    return false;
+  }
+  let constraint_fields = attribute
+    .arguments
+    .iter()
+    .find(|argument| argument.name.is_none() || argument.name.as_deref() == Some("fields"))
+    .map(|argument| match &argument.value {
+      Expression::Array { value, .. } => value
+        .iter()
+        .filter_map(|element| match element {
+          Expression::ConstantValue { value, .. } => Some(value.as_str()),
+          _ => None,
+        })
+        .collect(),
+      _ => vec![],
+    })
+    .unwrap_or_default();
+
+  constraint_fields.len() == fields.len()
+    && constraint_fields.iter().all(|field| fields.contains(field))
+}
+
+fn validate_fields_and_references(
+  db: &ParserDatabase,
+  relation_field: &RelationField,
+  relation: &RelationAttribute,
+  diagnostics: &mut Diagnostics,
+) {
+  let (fields, references) = match (&relation.fields, &relation.references) {
+    (None, None) => return,
+    (Some(fields), Some(references)) => (fields, references),
+    _ => {
+      diagnostics.push_error(invalid_relation(
+        format!("{} must define both `fields` and `references`.", relation_field.describe()),
+        relation.span,
+      ));
+      return;
+    }
+  };
+
+  if fields.len() != references.len() {
+    diagnostics.push_error(invalid_relation(
+      format!(
+        "{} must define as many `fields` as `references`, found {} and {}.",
+        relation_field.describe(),
+        fields.len(),
+        references.len()
+      ),
+      relation.span,
+    ));
+    return;
+  }
+
+  let target = relation_field.target;
+  let mut all_fields_exist = true;
+  for (field, reference) in fields.iter().zip(references) {
+    let field_ast = find_field(db, relation_field.model_id, field, diagnostics);
+    let reference_ast = find_field(db, relation_field.target_id, reference, diagnostics);
+    let (Some(field_ast), Some(reference_ast)) = (field_ast, reference_ast) else {
+      all_fields_exist = false;
+      continue;
+    };
+
+    if field_ast.field_type != reference_ast.field_type {
+      diagnostics.push_error(invalid_relation(
+        format!(
+          "The type of the field \"{}\" ({}) doesn't match the type of the referenced field \
+           \"{}.{}\" ({}).",
+          field_ast.name, field_ast.field_type, target.name, reference_ast.name,
+          reference_ast.field_type
+        ),
+        field.span,
+      ));
+    }
+  }
+
+  if !all_fields_exist {
+    return;
+  }
+
+  let reference_names: Vec<&str> = references.iter().map(|reference| reference.name).collect();
+  let is_unique = match reference_names.as_slice() {
+    [name] => target.fields.iter().any(|field| {
+      let is_unique = |attribute: &Attribute| attribute.name == "id" || attribute.name == "unique";
+      field.name == *name && field.attributes.iter().any(is_unique)
+    }),
+    _ => false,
+  } || target.attributes.iter().any(|attribute| is_unique_constraint(attribute, &reference_names));
+
+  if !is_unique {
+    diagnostics.push_error(invalid_relation(
+      format!(
+        "The referenced fields [{}] must be unique in the model \"{}\", \
+         e.g., with @id, @unique or @@unique.",
+        reference_names.join(", "),
+        target.name
+      ),
+      relation.span,
+    ));
+  }
+}
+
+fn validate_referential_actions(
+  db: &ParserDatabase,
+  relation_field: &RelationField,
+  relation: &RelationAttribute,
+  diagnostics: &mut Diagnostics,
+) {
+  let actions = [&relation.on_delete, &relation.on_update];
+  let actions = actions.into_iter().flatten();
+
+  // `fields` may be missing because it's invalid, which is already reported
+  if relation.fields.is_none() && relation.references.is_none() && !relation_field.is_invalid {
+    for action in actions {
+      diagnostics.push_error(invalid_relation(
+        format!(
+          "{} can't define referential actions, as it doesn't define `fields`.",
+          relation_field.describe()
+        ),
+        action.span,
+      ));
+    }
+    return;
+  }
+
+  let Some(datasource) = db.datasource() else {
+    return;
+  };
+  let Some(Spanned { value: provider, .. }) = &datasource.provider else {
+    return;
+  };
+  let relation_mode = match &datasource.relation_mode {
+    Some(relation_mode) => &relation_mode.value,
+    None => &provider.info().relation_mode,
+  };
+  let supported_actions = match relation_mode {
+    RelationMode::ForeignKeys => provider.info().referential_actions,
+    RelationMode::Prisma => EMULATED_REFERENTIAL_ACTIONS,
+  };
+
+  for action in actions.filter(|action| !supported_actions.contains(&action.action)) {
+    let mut error = DatamodelError::new(
+      ErrorCode::InvalidReferentialAction,
+      format!(
+        "The referential action {} is not supported by the provider {provider} \
+         with relationMode = {relation_mode}. Supported actions are: {}.",
+        action.action,
+        supported_actions.iter().map(|action| action.name()).collect::<Vec<_>>().join(", ")
+      ),
+      action.span,
+    );
+    if let Some(relation_mode) = &datasource.relation_mode {
+      error = error.with_related_span("relationMode defined here", relation_mode.span);
+    }
+    diagnostics.push_error(error);
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn validate(schema: &str) -> Vec<(ErrorCode, String, Span)> {
+    let ast = crate::parse_schema(String::from(schema)).unwrap();
+    let mut diagnostics = Diagnostics::new();
+    let db = ParserDatabase::new(&ast, &mut diagnostics);
+    validate_relations(&db, &mut diagnostics);
+    diagnostics
+      .errors()
+      .iter()
+      .map(|error| (error.code, error.message.clone(), error.span))
+      .collect()
+  }
+
+  fn with_datasource(provider: &str, url: &str, relation_mode: &str, models: &str) -> String {
+    format!(
+      "datasource db {{\n  provider = \"{}\"\n  url = \"{}\"\n{}}}\n{}",
+      provider, url, relation_mode, models
+    )
+  }
+
+  const BLOG: &str = r#"
+    model User {
+      id    Int    @id
+      email String @unique
+      posts Post[]
+    }
+
+    model Post {
+      id          Int    @id
+      authorId    Int
+      authorEmail String
+      author      User   @relation(fields: [authorId], references: [id], onDelete: Cascade)
+    }
+  "#;
+
+  #[test]
+  fn test_relation_attribute() {
+    let schema = r#"
+      model Post {
+        author User @relation("PostAuthor", fields: [authorId], references: [id],
+                              onUpdate: SetNull, map: "fk_author")
+      }
+    "#;
+    let ast = crate::parse_schema(String::from(schema)).unwrap();
+    let attribute = &ast.models[0].fields[0].attributes[0];
+    let mut diagnostics = Diagnostics::new();
+
+    assert_eq!(
+      RelationAttribute::from_attribute(attribute, &mut diagnostics),
+      RelationAttribute {
+        name: Some("PostAuthor"),
+        fields: Some(vec![FieldReference { name: "authorId", span: Span::of(schema, "authorId") }]),
+        references: Some(vec![FieldReference { name: "id", span: Span::new(97, 99) }]),
+        on_delete: None,
+        on_update: Some(ReferentialActionValue {
+          action: ReferentialAction::SetNull,
+          span: Span::of(schema, "SetNull"),
+        }),
+        map: Some("fk_author"),
+        span: attribute.span,
+      }
+    );
+    assert!(!diagnostics.has_errors());
+  }
+
+  #[test]
+  fn test_invalid_relation_attribute() {
+    let schema = r#"
+      model User {
+        id    Int    @id
+        posts Post[]
+      }
+
+      model Post {
+        authorId Int
+        author   User @relation(fields: authorId, references: [id], onDelete: Drop)
+      }
+    "#;
+    // every invalid argument is reported
+    assert_eq!(
+      validate(schema),
+      vec![
+        (
+          ErrorCode::InvalidRelation,
+          String::from("Expected a list of field names, e.g., [id]."),
+          Span::new(155, 163),
+        ),
+        (
+          ErrorCode::InvalidReferentialAction,
+          String::from(concat!(
+            r#"Unknown referential action "Drop", "#,
+            "expected one of: Cascade, Restrict, NoAction, SetNull, SetDefault."
+          )),
+          Span::of(schema, "Drop"),
+        ),
+      ]
+    );
+
+    let schema = schema.replace("fields: authorId", "fields: [authorId]");
+    assert_eq!(
+      validate(&schema),
+      vec![(
+        ErrorCode::InvalidReferentialAction,
+        String::from(concat!(
+          r#"Unknown referential action "Drop", "#,
+          "expected one of: Cascade, Restrict, NoAction, SetNull, SetDefault."
+        )),
+        Span::of(&schema, "Drop"),
+      )]
+    );
+  }
+
+  #[test]
+  fn test_valid_relations() {
+    assert_eq!(validate(BLOG), vec![]);
+
+    // one-to-one, and named relations between the same models
+    let schema = r#"
+      model User {
+        id      Int      @id
+        profile Profile?
+        written Post[]   @relation("Author")
+        edited  Post[]   @relation("Editor")
+      }
+
+      model Profile {
+        id     Int  @id
+        userId Int  @unique
+        user   User @relation(fields: [userId], references: [id])
+      }
+
+      model Post {
+        id       Int  @id
+        authorId Int
+        editorId Int
+        author   User @relation("Author", fields: [authorId], references: [id])
+        editor   User @relation(name: "Editor", fields: [editorId], references: [id])
+      }
+    "#;
+    assert_eq!(validate(schema), vec![]);
+
+    // compound references
+    let schema = r#"
+      model User {
+        firstName String
+        lastName  String
+        posts     Post[]
+
+        @@id([firstName, lastName])
+      }
+
+      model Post {
+        id              Int    @id
+        authorFirstName String
+        authorLastName  String
+        author          User   @relation(fields: [authorFirstName, authorLastName],
+                                         references: [firstName, lastName])
+      }
+    "#;
+    assert_eq!(validate(schema), vec![]);
+  }
+
+  #[test]
+  fn test_relation_sides() {
+    let schema = r#"
+      model User {
+        id    Int    @id
+        posts Post[]
+      }
+
+      model Post {
+        id     Int  @id
+        author User
+      }
+
+      model Tag {
+        id Int @id
+        owner User
+        other Post
+      }
+    "#;
+    let errors: Vec<String> = validate(schema).into_iter().map(|(_, message, _)| message).collect();
+    assert_eq!(
+      errors,
+      vec![
+        concat!(
+          r#"The relation field "author" of the model "Post" "#,
+          "must define `fields` and `references`."
+        ),
+        concat!(
+          r#"The relation field "owner" of the model "Tag" "#,
+          r#"is missing an opposite relation field on the model "User"."#
+        ),
+        concat!(
+          r#"The relation field "other" of the model "Tag" "#,
+          r#"is missing an opposite relation field on the model "Post"."#
+        ),
+      ]
+    );
+
+    let schema = r#"
+      model User {
+        id      Int     @id
+        profile Profile
+      }
+
+      model Profile {
+        id   Int  @id
+        user User
+      }
+    "#;
+    assert_eq!(
+      validate(schema),
+      vec![(
+        ErrorCode::InvalidRelation,
+        String::from(concat!(
+          r#"The relation field "user" of the model "Profile" is part of a one-to-one relation, "#,
+          "so one of its sides must define `fields` and `references`."
+        )),
+        Span::of(schema, "user User"),
+      )]
+    );
+
+    let relation = "posts Post[] @relation(fields: [id], references: [id])";
+    let schema = BLOG.replace("posts Post[]", relation);
+    let (_, message, _) = &validate(&schema)[0];
+    assert_eq!(
+      message,
+      concat!(
+        r#"The relation field "posts" of the model "User" is a list, "#,
+        "so it can't define `fields` and `references`."
+      )
+    );
+
+    // ambiguous relations
+    let schema = r#"
+      model User {
+        id      Int    @id
+        written Post[]
+        edited  Post[]
+      }
+
+      model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+      }
+    "#;
+    let errors = validate(schema);
+    assert_eq!(errors.len(), 3);
+    let (_, message, _) = &errors[0];
+    let expected = r#"The relation field "written" of the model "User" is ambiguous"#;
+    assert!(message.starts_with(expected));
+  }
+
+  #[test]
+  fn test_self_relations() {
+    let schema = r#"
+      model User {
+        id          Int   @id
+        mentorId    Int?  @unique
+        mentor      User? @relation(fields: [mentorId], references: [id])
+        mentee      User?
+        followers   User[] @relation("UserFollows")
+        following   User[] @relation("UserFollows")
+      }
+    "#;
+    assert_eq!(validate(schema), vec![]);
+
+    // without names, the three fields can't be paired
+    let schema = r#"
+      model User {
+        id          Int   @id
+        mentorId    Int?  @unique
+        mentor      User? @relation(fields: [mentorId], references: [id])
+        mentee      User?
+        followers   User[]
+      }
+    "#;
+    let errors = validate(schema);
+    assert_eq!(
+      errors.iter().map(|(_, _, span)| *span).collect::<Vec<_>>(),
+      vec![
+        Span::of(schema, "mentor      User? @relation(fields: [mentorId], references: [id])"),
+        Span::of(schema, "mentee      User?"),
+        Span::of(schema, "followers   User[]"),
+      ]
+    );
+    assert_eq!(
+      errors[2],
+      (
+        ErrorCode::InvalidRelation,
+        String::from(concat!(
+          r#"The relation field "followers" of the model "User" is ambiguous, as the model "#,
+          r#""User" has more than two relation fields to itself. Give both sides of each "#,
+          r#"relation the same name, e.g., @relation("UserFollows")."#
+        )),
+        Span::of(schema, "followers   User[]"),
+      )
+    );
+  }
+
+  #[test]
+  fn test_fields_and_references() {
+    let schema = BLOG.replace("fields: [authorId], ", "");
+    assert_eq!(
+      validate(&schema),
+      vec![(
+        ErrorCode::InvalidRelation,
+        String::from(concat!(
+          r#"The relation field "author" of the model "Post" "#,
+          "must define both `fields` and `references`."
+        )),
+        Span::of(&schema, "@relation(references: [id], onDelete: Cascade)"),
+      )]
+    );
+
+    let schema = BLOG.replace("[authorId]", "[authorId, authorEmail]");
+    let (_, message, _) = &validate(&schema)[0];
+    assert_eq!(
+      message,
+      concat!(
+        r#"The relation field "author" of the model "Post" "#,
+        "must define as many `fields` as `references`, found 2 and 1."
+      )
+    );
+
+    let schema = BLOG.replace("[authorId]", "[authorID]").replace("[id]", "[nickname]");
+    assert_eq!(
+      validate(&schema),
+      vec![
+        (
+          ErrorCode::InvalidRelation,
+          String::from(concat!(
+            r#"The field "authorID" doesn't exist in the model "Post". "#,
+            r#"Did you mean "authorId"?"#
+          )),
+          Span::of(&schema, "authorID"),
+        ),
+        (
+          ErrorCode::InvalidRelation,
+          String::from(r#"The field "nickname" doesn't exist in the model "User"."#),
+          Span::of(&schema, "nickname"),
+        ),
+      ]
+    );
+
+    // relation fields can't be referenced, so they're never suggested
+    let schema = BLOG.replace("[authorId]", "[autho]");
+    let (_, message, _) = &validate(&schema)[0];
+    assert_eq!(message, r#"The field "autho" doesn't exist in the model "Post"."#);
+
+    let schema = BLOG.replace("[authorId]", "[authorEmail]");
+    assert_eq!(
+      validate(&schema),
+      vec![(
+        ErrorCode::InvalidRelation,
+        String::from(concat!(
+          r#"The type of the field "authorEmail" (String) doesn't match "#,
+          r#"the type of the referenced field "User.id" (Int)."#
+        )),
+        Span::new(231, 242),
+      )]
+    );
+  }
+
+  #[test]
+  fn test_unique_references() {
+    let schema = BLOG.replace("email String @unique", "email String");
+    let schema =
+      schema.replace("[authorId], references: [id]", "[authorEmail], references: [email]");
+    assert_eq!(
+      validate(&schema),
+      vec![(
+        ErrorCode::InvalidRelation,
+        String::from(concat!(
+          r#"The referenced fields [email] must be unique in the model "User", "#,
+          "e.g., with @id, @unique or @@unique."
+        )),
+        Span::of(
+          &schema,
+          "@relation(fields: [authorEmail], references: [email], onDelete: Cascade)"
+        ),
+      )]
+    );
+
+    let schema = schema.replace("posts Post[]", "posts Post[]\n\n      @@unique([email])");
+    assert_eq!(validate(&schema), vec![]);
+  }
+
+  #[test]
+  fn test_referential_actions() {
+    // onDelete on the side without fields
+    let schema = r#"
+      model User {
+        id    Int    @id
+        posts Post[] @relation(onDelete: Cascade)
+      }
+
+      model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+      }
+    "#;
+    assert_eq!(
+      validate(schema),
+      vec![(
+        ErrorCode::InvalidRelation,
+        String::from(concat!(
+          r#"The relation field "posts" of the model "User" "#,
+          "can't define referential actions, as it doesn't define `fields`."
+        )),
+        Span::of(schema, "Cascade"),
+      )]
+    );
+
+    let models = BLOG.replace("onDelete: Cascade", "onDelete: SetDefault, onUpdate: Restrict");
+    let schema = with_datasource("postgres", "postgres://localhost/blog", "", &models);
+    assert_eq!(validate(&schema), vec![]);
+
+    let schema = with_datasource("mysql", "mysql://localhost/blog", "", &models);
+    assert_eq!(
+      validate(&schema),
+      vec![(
+        ErrorCode::InvalidReferentialAction,
+        String::from(concat!(
+          r#"The referential action SetDefault is not supported by the provider "mysql" "#,
+          r#"with relationMode = "foreignKeys". "#,
+          "Supported actions are: Cascade, Restrict, NoAction, SetNull."
+        )),
+        Span::of(&schema, "SetDefault"),
+      )]
+    );
+
+    let schema = with_datasource("sqlserver", "sqlserver://localhost", "", &models);
+    let errors: Vec<Span> = validate(&schema).into_iter().map(|(_, _, span)| span).collect();
+    assert_eq!(errors, vec![Span::of(&schema, "Restrict")]);
+
+    let relation_mode = "  relationMode = \"prisma\"\n";
+    let schema = with_datasource("postgres", "postgres://localhost/blog", relation_mode, &models);
+    let (_, message, span) = &validate(&schema)[0];
+    assert!(message.contains(r#"with relationMode = "prisma""#));
+    assert_eq!(*span, Span::of(&schema, "SetDefault"));
+
+    // a disallowed action is reported next to an invalid argument of the same attribute
+    let invalid_models = models.replace("onUpdate: Restrict", "onUpdate: Foo");
+    let invalid_schema =
+      with_datasource("postgres", "postgres://localhost/blog", relation_mode, &invalid_models);
+    let errors: Vec<(ErrorCode, Span)> =
+      validate(&invalid_schema).into_iter().map(|(code, _, span)| (code, span)).collect();
+    assert_eq!(
+      errors,
+      vec![
+        (ErrorCode::InvalidReferentialAction, Span::of(&invalid_schema, "Foo")),
+        (ErrorCode::InvalidReferentialAction, Span::of(&invalid_schema, "SetDefault")),
+      ]
+    );
+
+    // the relation mode of the datasource is pointed at
+    let ast = crate::parse_schema(schema.clone()).unwrap();
+    let mut diagnostics = Diagnostics::new();
+    validate_relations(&ParserDatabase::new(&ast, &mut diagnostics), &mut diagnostics);
+    let related_span = diagnostics.errors()[0].related_spans[0].span;
+    assert_eq!(related_span, Span::of(&schema, r#""prisma""#));
+  }
+}